@@ -1,8 +1,6 @@
 use super::ExecutedBlock;
 use alloy_primitives::{
-    keccak256,
-    map::{HashMap, HashSet},
-    Address, BlockNumber, Bytes, StorageKey, StorageValue, B256,
+    keccak256, map::HashMap, Address, BlockNumber, Bytes, StorageKey, StorageValue, B256,
 };
 use reth_errors::ProviderResult;
 use reth_primitives::{Account, Bytecode, NodePrimitives};
@@ -11,7 +9,8 @@ use reth_storage_api::{
     StorageRootProvider,
 };
 use reth_trie::{
-    updates::TrieUpdates, AccountProof, HashedPostState, HashedStorage, MultiProof, TrieInput,
+    updates::TrieUpdates, AccountProof, HashedPostState, HashedStorage, MultiProof, ProofTargets,
+    StorageMultiProof, TrieInput,
 };
 use std::sync::OnceLock;
 
@@ -167,6 +166,20 @@ macro_rules! impl_state_provider {
                 hashed_storage.extend(&storage);
                 self.historical.storage_proof(address, slot, hashed_storage)
             }
+
+            // TODO: Currently this does not reuse available in-memory trie nodes.
+            fn storage_multiproof(
+                &self,
+                address: Address,
+                slots: &[B256],
+                storage: HashedStorage,
+            ) -> ProviderResult<StorageMultiProof> {
+                let state = &self.trie_state().state;
+                let mut hashed_storage =
+                    state.storages.get(&keccak256(address)).cloned().unwrap_or_default();
+                hashed_storage.extend(&storage);
+                self.historical.storage_multiproof(address, slots, hashed_storage)
+            }
         }
 
         impl $($tokens)* StateProofProvider for $type {
@@ -184,7 +197,7 @@ macro_rules! impl_state_provider {
             fn multiproof(
                 &self,
                 mut input: TrieInput,
-                targets: HashMap<B256, HashSet<B256>>,
+                targets: ProofTargets,
             ) -> ProviderResult<MultiProof> {
                 let MemoryOverlayTrieState { nodes, state } = self.trie_state().clone();
                 input.prepend_cached(nodes, state);
@@ -26,7 +26,7 @@ use reth_network_peers::{
     base_nodes, base_testnet_nodes, holesky_nodes, mainnet_nodes, op_nodes, op_testnet_nodes,
     sepolia_nodes, NodeRecord,
 };
-use reth_primitives_traits::SealedHeader;
+use reth_primitives_traits::{Account, SealedHeader};
 use reth_trie_common::root::state_root_ref_unhashed;
 
 use crate::{constants::MAINNET_DEPOSIT_CONTRACT, once_cell_set, EthChainSpec, LazyLock, OnceLock};
@@ -257,6 +257,14 @@ impl ChainSpec {
         &self.genesis
     }
 
+    /// Returns the account allocated to the given address in the genesis block, if any.
+    ///
+    /// This can be used to check that the accounts of the genesis allocation are present in the
+    /// state of a database initialized from this chain spec.
+    pub fn genesis_account(&self, address: &Address) -> Option<Account> {
+        self.genesis.alloc.get(address).map(Account::from)
+    }
+
     /// Get the header for the genesis block.
     pub fn genesis_header(&self) -> &Header {
         self.genesis_header.get_or_init(|| self.make_genesis_header())
@@ -949,11 +957,14 @@ pub fn test_fork_ids(spec: &ChainSpec, cases: &[(Head, ForkId)]) {
 #[cfg(test)]
 mod tests {
     use core::ops::Deref;
-    use std::{collections::HashMap, str::FromStr};
+    use std::{
+        collections::{BTreeMap, HashMap},
+        str::FromStr,
+    };
 
     use alloy_chains::Chain;
     use alloy_genesis::{ChainConfig, GenesisAccount};
-    use alloy_primitives::{b256, hex};
+    use alloy_primitives::{b256, hex, keccak256, Bytes};
     use alloy_trie::EMPTY_ROOT_HASH;
     use reth_ethereum_forks::{ForkCondition, ForkHash, ForkId, Head};
     use reth_trie_common::TrieAccount;
@@ -2310,4 +2321,36 @@ Post-merge hard forks (timestamp based):
             .all(|(expected, actual)| &**expected == *actual));
         assert_eq!(expected_hardforks.len(), hardforks.len());
     }
+
+    #[test]
+    fn genesis_account() {
+        let with_code = Address::random();
+        let with_balance = Address::random();
+        let code = Bytes::from_static(&[0x60, 0x00]);
+        let genesis = Genesis {
+            alloc: BTreeMap::from([
+                (
+                    with_code,
+                    GenesisAccount {
+                        nonce: Some(1),
+                        code: Some(code.clone()),
+                        ..Default::default()
+                    },
+                ),
+                (with_balance, GenesisAccount { balance: U256::from(7), ..Default::default() }),
+            ]),
+            ..Default::default()
+        };
+        let spec = ChainSpecBuilder::mainnet().genesis(genesis).build();
+
+        assert_eq!(
+            spec.genesis_account(&with_code),
+            Some(Account { nonce: 1, balance: U256::ZERO, bytecode_hash: Some(keccak256(&code)) })
+        );
+        assert_eq!(
+            spec.genesis_account(&with_balance),
+            Some(Account { nonce: 0, balance: U256::from(7), bytecode_hash: None })
+        );
+        assert_eq!(spec.genesis_account(&Address::random()), None);
+    }
 }
@@ -1,8 +1,6 @@
 use alloc::vec::Vec;
 use alloy_primitives::{
-    keccak256,
-    map::{HashMap, HashSet},
-    Address, BlockNumber, Bytes, StorageKey, B256, U256,
+    keccak256, map::HashMap, Address, BlockNumber, Bytes, StorageKey, B256, U256,
 };
 use reth_primitives::{Account, Bytecode};
 use reth_storage_api::{
@@ -11,8 +9,8 @@ use reth_storage_api::{
 };
 use reth_storage_errors::provider::ProviderResult;
 use reth_trie::{
-    updates::TrieUpdates, AccountProof, HashedPostState, HashedStorage, MultiProof, StorageProof,
-    TrieInput,
+    updates::TrieUpdates, AccountProof, HashedPostState, HashedStorage, MultiProof, ProofTargets,
+    StorageMultiProof, StorageProof, TrieInput,
 };
 
 /// Mock state for testing
@@ -112,6 +110,15 @@ impl StorageRootProvider for StateProviderTest {
     ) -> ProviderResult<StorageProof> {
         unimplemented!("proof generation is not supported")
     }
+
+    fn storage_multiproof(
+        &self,
+        _address: Address,
+        _slots: &[B256],
+        _hashed_storage: HashedStorage,
+    ) -> ProviderResult<StorageMultiProof> {
+        unimplemented!("proof generation is not supported")
+    }
 }
 
 impl StateProofProvider for StateProviderTest {
@@ -124,11 +131,7 @@ impl StateProofProvider for StateProviderTest {
         unimplemented!("proof generation is not supported")
     }
 
-    fn multiproof(
-        &self,
-        _input: TrieInput,
-        _targets: HashMap<B256, HashSet<B256>>,
-    ) -> ProviderResult<MultiProof> {
+    fn multiproof(&self, _input: TrieInput, _targets: ProofTargets) -> ProviderResult<MultiProof> {
         unimplemented!("proof generation is not supported")
     }
 
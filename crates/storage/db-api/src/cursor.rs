@@ -72,6 +72,14 @@ pub trait DbDupCursorRO<T: DupSort> {
     /// Positions the cursor at the next duplicate value of the current key.
     fn next_dup_val(&mut self) -> ValueOnlyResult<T>;
 
+    /// Positions the cursor at the previous duplicate value of the current key.
+    fn prev_dup_val(&mut self) -> ValueOnlyResult<T>;
+
+    /// Positions the cursor at the last duplicate value of the provided key, returning it.
+    ///
+    /// Returns `None` if the key doesn't exist.
+    fn last_dup(&mut self, key: T::Key) -> ValueOnlyResult<T>;
+
     /// Positions the cursor at the entry greater than or equal to the provided key/subkey pair.
     ///
     /// # Note
@@ -98,6 +106,41 @@ pub trait DbDupCursorRO<T: DupSort> {
     ) -> Result<DupWalker<'_, T, Self>, DatabaseError>
     where
         Self: Sized;
+
+    /// Get an iterator that walks through the dup table, yielding each key together with all of
+    /// its duplicate values.
+    ///
+    /// If `start_key` is `None`, then the walker will start from the first entry of the table,
+    /// otherwise it starts at the key greater than or equal to the provided key.
+    ///
+    /// Each iteration allocates a new [`Vec`] for the group. Use
+    /// [`DupGroupWalker::next_group_into`] to reuse a single buffer instead.
+    fn walk_grouped(
+        &mut self,
+        start_key: Option<T::Key>,
+    ) -> Result<DupGroupWalker<'_, T, Self>, DatabaseError>
+    where
+        Self: DbCursorRO<T> + Sized,
+    {
+        let start = match start_key {
+            Some(key) => self.seek(key).transpose(),
+            None => self.first().transpose(),
+        };
+        Ok(DupGroupWalker::new(self, start))
+    }
+
+    /// Get an iterator that walks through the duplicate values of a single key in descending
+    /// order, starting from the last one.
+    ///
+    /// If the key has a single value, only that value is yielded. If the key doesn't exist, the
+    /// iterator is empty.
+    fn walk_dup_rev(&mut self, key: T::Key) -> Result<ReverseDupWalker<'_, T, Self>, DatabaseError>
+    where
+        Self: Sized,
+    {
+        let start = self.last_dup(key).transpose();
+        Ok(ReverseDupWalker::new(self, start))
+    }
 }
 
 /// Read write cursor over table.
@@ -118,6 +161,18 @@ pub trait DbCursorRW<T: Table> {
 
     /// Delete current value that cursor points to
     fn delete_current(&mut self) -> Result<(), DatabaseError>;
+
+    /// Replace the value that cursor points to, keeping its key.
+    ///
+    /// This writes in place at the cursor position, without seeking to the key again. It is
+    /// meant for transforming the rows of a table while walking it.
+    ///
+    /// For a DUPSORT table, only the current duplicate value is replaced. The new value is sorted
+    /// among the other values of the key, so if it compares differently (e.g. it has another
+    /// subkey), the cursor moves along with it.
+    ///
+    /// Returns an error if the cursor is not positioned at an item.
+    fn update_current(&mut self, value: T::Value) -> Result<(), DatabaseError>;
 }
 
 /// Read Write Cursor over `DupSorted` table.
@@ -362,3 +417,115 @@ impl<T: DupSort, CURSOR: DbDupCursorRO<T>> Iterator for DupWalker<'_, T, CURSOR>
         self.cursor.next_dup().transpose()
     }
 }
+
+/// Provides a reverse iterator over the duplicate values of a single key of a `DupSort` table.
+///
+/// Also check [`DbDupCursorRO::walk_dup_rev`].
+pub struct ReverseDupWalker<'cursor, T: DupSort, CURSOR: DbDupCursorRO<T>> {
+    /// Cursor to be used to walk through the table.
+    cursor: &'cursor mut CURSOR,
+    /// Next value to yield.
+    next: Option<Result<T::Value, DatabaseError>>,
+}
+
+impl<T, CURSOR> fmt::Debug for ReverseDupWalker<'_, T, CURSOR>
+where
+    T: DupSort,
+    CURSOR: DbDupCursorRO<T> + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ReverseDupWalker")
+            .field("cursor", &self.cursor)
+            .field("next", &self.next)
+            .finish()
+    }
+}
+
+impl<'cursor, T: DupSort, CURSOR: DbDupCursorRO<T>> ReverseDupWalker<'cursor, T, CURSOR> {
+    /// construct `ReverseDupWalker`
+    pub fn new(
+        cursor: &'cursor mut CURSOR,
+        start: Option<Result<T::Value, DatabaseError>>,
+    ) -> Self {
+        Self { cursor, next: start }
+    }
+}
+
+impl<T: DupSort, CURSOR: DbDupCursorRO<T>> Iterator for ReverseDupWalker<'_, T, CURSOR> {
+    type Item = Result<T::Value, DatabaseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = self.next.take()?;
+        if next.is_ok() {
+            self.next = self.cursor.prev_dup_val().transpose();
+        }
+        Some(next)
+    }
+}
+
+/// Provides an iterator to `Cursor` when handling a `DupSort` table, which yields every key
+/// together with all of its duplicate values.
+///
+/// Also check [`DbDupCursorRO::walk_grouped`].
+pub struct DupGroupWalker<'cursor, T: DupSort, CURSOR: DbDupCursorRO<T>> {
+    /// Cursor to be used to walk through the table.
+    cursor: &'cursor mut CURSOR,
+    /// First `(key, value)` of the next group.
+    next: IterPairResult<T>,
+}
+
+impl<T, CURSOR> fmt::Debug for DupGroupWalker<'_, T, CURSOR>
+where
+    T: DupSort,
+    CURSOR: DbDupCursorRO<T> + fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DupGroupWalker")
+            .field("cursor", &self.cursor)
+            .field("next", &self.next)
+            .finish()
+    }
+}
+
+impl<'cursor, T: DupSort, CURSOR: DbDupCursorRO<T>> DupGroupWalker<'cursor, T, CURSOR> {
+    /// construct `DupGroupWalker`
+    pub fn new(cursor: &'cursor mut CURSOR, start: IterPairResult<T>) -> Self {
+        Self { cursor, next: start }
+    }
+
+    /// Reads the next group into `values`, returning its key.
+    ///
+    /// `values` is cleared before the group is read, which allows reusing the same buffer across
+    /// groups.
+    pub fn next_group_into(
+        &mut self,
+        values: &mut Vec<T::Value>,
+    ) -> Option<Result<T::Key, DatabaseError>> {
+        let (key, value) = match self.next.take()? {
+            Ok(entry) => entry,
+            Err(err) => return Some(Err(err)),
+        };
+
+        values.clear();
+        values.push(value);
+        loop {
+            match self.cursor.next_dup_val() {
+                Ok(Some(value)) => values.push(value),
+                Ok(None) => break,
+                Err(err) => return Some(Err(err)),
+            }
+        }
+
+        self.next = self.cursor.next_no_dup().transpose();
+        Some(Ok(key))
+    }
+}
+
+impl<T: DupSort, CURSOR: DbDupCursorRO<T>> Iterator for DupGroupWalker<'_, T, CURSOR> {
+    type Item = Result<(T::Key, Vec<T::Value>), DatabaseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut values = Vec::new();
+        self.next_group_into(&mut values).map(|res| res.map(|key| (key, values)))
+    }
+}
@@ -65,10 +65,20 @@ impl DatabaseMetadataValue {
 pub trait DatabaseMetadata {
     /// Returns a metadata type, [`DatabaseMetadataValue`] for the database.
     fn metadata(&self) -> DatabaseMetadataValue;
+
+    /// Returns the number of read transactions that can still be opened concurrently, if the
+    /// database limits them.
+    fn available_readers(&self) -> Option<usize> {
+        None
+    }
 }
 
 impl<DB: DatabaseMetadata> DatabaseMetadata for Arc<DB> {
     fn metadata(&self) -> DatabaseMetadataValue {
         <DB as DatabaseMetadata>::metadata(self)
     }
+
+    fn available_readers(&self) -> Option<usize> {
+        <DB as DatabaseMetadata>::available_readers(self)
+    }
 }
@@ -192,6 +192,14 @@ impl<T: DupSort> DbDupCursorRO<T> for CursorMock {
         Ok(None)
     }
 
+    fn prev_dup_val(&mut self) -> ValueOnlyResult<T> {
+        Ok(None)
+    }
+
+    fn last_dup(&mut self, _key: <T as Table>::Key) -> ValueOnlyResult<T> {
+        Ok(None)
+    }
+
     fn seek_by_key_subkey(
         &mut self,
         _key: <T as Table>::Key,
@@ -237,6 +245,10 @@ impl<T: Table> DbCursorRW<T> for CursorMock {
     fn delete_current(&mut self) -> Result<(), DatabaseError> {
         Ok(())
     }
+
+    fn update_current(&mut self, _value: <T as Table>::Value) -> Result<(), DatabaseError> {
+        Ok(())
+    }
 }
 
 impl<T: DupSort> DbDupCursorRW<T> for CursorMock {
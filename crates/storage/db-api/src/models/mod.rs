@@ -108,6 +108,10 @@ impl Encode for String {
     fn encode(self) -> Self::Encoded {
         self.into_bytes()
     }
+
+    fn encode_to(self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(self.as_bytes());
+    }
 }
 
 impl Decode for String {
@@ -129,6 +133,10 @@ impl Encode for StoredNibbles {
         // so we can just use the implementation of `Into<Vec<u8>>` to reuse the buffer.
         self.0.into()
     }
+
+    fn encode_to(self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(self.0.as_slice());
+    }
 }
 
 impl Decode for StoredNibbles {
@@ -146,6 +154,10 @@ impl Encode for StoredNibblesSubKey {
         self.to_compact(&mut buf);
         buf
     }
+
+    fn encode_to(self, buf: &mut Vec<u8>) {
+        self.to_compact(buf);
+    }
 }
 
 impl Decode for StoredNibblesSubKey {
@@ -179,6 +191,10 @@ impl Encode for ClientVersion {
         self.to_compact(&mut buf);
         buf
     }
+
+    fn encode_to(self, buf: &mut Vec<u8>) {
+        self.to_compact(buf);
+    }
 }
 
 impl Decode for ClientVersion {
@@ -305,6 +321,41 @@ add_wrapper_struct!((ClientVersion, CompactClientVersion));
 
 #[cfg(test)]
 mod tests {
+    use super::*;
+    use crate::models::{sharded_key::ShardedKey, storage_sharded_key::StorageShardedKey};
+
+    fn assert_encode_to<K: Encode + Clone>(key: K) {
+        let mut buf = vec![0xff];
+        key.clone().encode_to(&mut buf);
+        assert_eq!(buf[0], 0xff, "encode_to must append to the buffer");
+        assert_eq!(&buf[1..], key.encode().as_ref());
+    }
+
+    #[test]
+    fn test_encode_to() {
+        assert_encode_to(42u64);
+        assert_encode_to(Address::with_last_byte(1));
+        assert_encode_to(B256::with_last_byte(1));
+        assert_encode_to(String::from("reth"));
+        assert_encode_to(StoredNibbles::from(vec![1, 2, 3]));
+        assert_encode_to(StoredNibblesSubKey::from(vec![1, 2, 3]));
+        assert_encode_to(ClientVersion::default());
+        assert_encode_to(ShardedKey::new(Address::with_last_byte(1), 10));
+        assert_encode_to(StorageShardedKey::new(
+            Address::with_last_byte(1),
+            B256::with_last_byte(2),
+            10,
+        ));
+
+        // A single buffer can be reused across keys.
+        let mut buf = Vec::new();
+        for block in 0..3u64 {
+            buf.clear();
+            ShardedKey::new(Address::with_last_byte(1), block).encode_to(&mut buf);
+            assert_eq!(buf, ShardedKey::new(Address::with_last_byte(1), block).encode());
+        }
+    }
+
     // each value in the database has an extra field named flags that encodes metadata about other
     // fields in the value, e.g. offset and length.
     //
@@ -51,6 +51,11 @@ impl<T: Encode> Encode for ShardedKey<T> {
         buf.extend_from_slice(&self.highest_block_number.to_be_bytes());
         buf
     }
+
+    fn encode_to(self, buf: &mut Vec<u8>) {
+        self.key.encode_to(buf);
+        buf.extend_from_slice(&self.highest_block_number.to_be_bytes());
+    }
 }
 
 impl<T: Decode> Decode for ShardedKey<T> {
@@ -63,6 +63,12 @@ impl Encode for StorageShardedKey {
         buf.extend_from_slice(&self.sharded_key.highest_block_number.to_be_bytes());
         buf
     }
+
+    fn encode_to(self, buf: &mut Vec<u8>) {
+        self.address.encode_to(buf);
+        self.sharded_key.key.encode_to(buf);
+        buf.extend_from_slice(&self.sharded_key.highest_block_number.to_be_bytes());
+    }
 }
 
 impl Decode for StorageShardedKey {
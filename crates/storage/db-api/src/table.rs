@@ -53,6 +53,14 @@ pub trait Encode: Send + Sync + Sized + Debug {
 
     /// Encodes data going into the database.
     fn encode(self) -> Self::Encoded;
+
+    /// Encodes data going into the database, appending it to the given buffer.
+    ///
+    /// This allows reusing a single buffer across many keys. The buffer is not cleared before
+    /// encoding.
+    fn encode_to(self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(self.encode().as_ref());
+    }
 }
 
 /// Trait that will transform the data to be read from the DB.
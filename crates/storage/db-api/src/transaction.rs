@@ -20,6 +20,22 @@ pub trait DbTx: Send + Sync {
     fn abort(self);
     /// Iterate over read only values in table.
     fn cursor_read<T: Table>(&self) -> Result<Self::Cursor<T>, DatabaseError>;
+    /// Folds over all entries of the table in key order, starting from `init`.
+    ///
+    /// Entries are decoded one at a time, so no intermediate collection is allocated.
+    fn fold<T: Table, A>(
+        &self,
+        init: A,
+        mut f: impl FnMut(A, T::Key, T::Value) -> A,
+    ) -> Result<A, DatabaseError> {
+        let mut cursor = self.cursor_read::<T>()?;
+        let mut acc = init;
+        for entry in cursor.walk(None)? {
+            let (key, value) = entry?;
+            acc = f(acc, key, value);
+        }
+        Ok(acc)
+    }
     /// Iterate over read only values in dup sorted table.
     fn cursor_dup_read<T: DupSort>(&self) -> Result<Self::DupCursor<T>, DatabaseError>;
     /// Returns number of entries in the table.
@@ -177,6 +177,32 @@ impl<K: TransactionKind, T: DupSort> DbDupCursorRO<T> for Cursor<K, T> {
             .transpose()
     }
 
+    /// Returns the previous `value` of a duplicate `key`.
+    fn prev_dup_val(&mut self) -> ValueOnlyResult<T> {
+        self.inner
+            .prev_dup()
+            .map_err(|e| DatabaseError::Read(e.into()))?
+            .map(decode_value::<T>)
+            .transpose()
+    }
+
+    /// Returns the last `value` of a duplicate `key`.
+    fn last_dup(&mut self, key: <T as Table>::Key) -> ValueOnlyResult<T> {
+        if self
+            .inner
+            .set::<()>(key.encode().as_ref())
+            .map_err(|e| DatabaseError::Read(e.into()))?
+            .is_none()
+        {
+            return Ok(None)
+        }
+        self.inner
+            .last_dup()
+            .map_err(|e| DatabaseError::Read(e.into()))?
+            .map(decode_one::<T>)
+            .transpose()
+    }
+
     fn seek_by_key_subkey(
         &mut self,
         key: <T as Table>::Key,
@@ -314,6 +340,37 @@ impl<T: Table> DbCursorRW<T> for Cursor<RW, T> {
             this.inner.del(WriteFlags::CURRENT).map_err(|e| DatabaseError::Delete(e.into()))
         })
     }
+
+    /// Replaces the value at the current cursor position using `MDBX_CURRENT`.
+    ///
+    /// MDBX requires the key to be passed along and to match the current key, so it is read from
+    /// the current position first.
+    fn update_current(&mut self, value: T::Value) -> Result<(), DatabaseError> {
+        let Some((key, _)) = self
+            .inner
+            .get_current::<Cow<'_, [u8]>, ()>()
+            .map_err(|e| DatabaseError::Read(e.into()))?
+        else {
+            return Err(DatabaseError::CursorNotPositioned(T::NAME))
+        };
+        let key = key.into_owned();
+        let value = compress_to_buf_or_ref!(self, value);
+        self.execute_with_operation_metric(
+            Operation::CursorUpdateCurrent,
+            Some(value.unwrap_or(&self.buf).len()),
+            |this| {
+                this.inner.put(&key, value.unwrap_or(&this.buf), WriteFlags::CURRENT).map_err(|e| {
+                    DatabaseWriteError {
+                        info: e.into(),
+                        operation: DatabaseWriteOperation::CursorUpdateCurrent,
+                        table_name: T::NAME,
+                        key,
+                    }
+                    .into()
+                })
+            },
+        )
+    }
 }
 
 impl<T: DupSort> DbDupCursorRW<T> for Cursor<RW, T> {
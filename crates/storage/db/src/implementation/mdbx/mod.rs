@@ -26,7 +26,7 @@ use std::{
     ops::{Deref, Range},
     path::Path,
     sync::Arc,
-    time::{SystemTime, UNIX_EPOCH},
+    time::{Duration, SystemTime, UNIX_EPOCH},
 };
 use tx::Tx;
 
@@ -97,6 +97,16 @@ pub struct DatabaseArguments {
     ///
     /// This flag affects only at environment opening but can't be changed after.
     exclusive: Option<bool>,
+    /// Expected page size of the database, in bytes. If [None], the page size is not checked.
+    ///
+    /// The page size of an existing database is fixed when it is created, and MDBX opens it with
+    /// that page size regardless of the configured geometry.
+    expected_page_size: Option<usize>,
+    /// Maximum number of reader slots, i.e. concurrent read transactions. If [None], the default
+    /// value is used.
+    ///
+    /// MDBX may round the number up to fill the lock file page.
+    max_readers: Option<u64>,
 }
 
 impl Default for DatabaseArguments {
@@ -119,6 +129,8 @@ impl DatabaseArguments {
             log_level: None,
             max_read_transaction_duration: None,
             exclusive: None,
+            expected_page_size: None,
+            max_readers: None,
         }
     }
 
@@ -159,12 +171,48 @@ impl DatabaseArguments {
         self
     }
 
+    /// Set the page size that the database is expected to have.
+    ///
+    /// Opening a database with a different page size fails with
+    /// [`DatabaseError::PageSizeMismatch`].
+    pub const fn with_expected_page_size(mut self, expected_page_size: Option<usize>) -> Self {
+        self.expected_page_size = expected_page_size;
+        self
+    }
+
+    /// Set the maximum number of concurrent read transactions.
+    pub const fn with_max_readers(mut self, max_readers: Option<u64>) -> Self {
+        self.max_readers = max_readers;
+        self
+    }
+
     /// Returns the client version if any.
     pub const fn client_version(&self) -> &ClientVersion {
         &self.client_version
     }
 }
 
+/// Retry policy for opening read transactions with [`DatabaseEnv::tx_retry`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Maximum number of retries before the error is returned.
+    pub max_retries: usize,
+    /// Backoff before the first retry. It is doubled on every subsequent retry.
+    pub initial_backoff: Duration,
+    /// Upper bound for the backoff between two retries.
+    pub max_backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 5,
+            initial_backoff: Duration::from_millis(10),
+            max_backoff: Duration::from_secs(1),
+        }
+    }
+}
+
 /// Wrapper for the libmdbx environment: [Environment]
 #[derive(Debug)]
 pub struct DatabaseEnv {
@@ -280,9 +328,34 @@ impl DatabaseMetadata for DatabaseEnv {
     fn metadata(&self) -> DatabaseMetadataValue {
         DatabaseMetadataValue::new(self.freelist().ok())
     }
+
+    fn available_readers(&self) -> Option<usize> {
+        let max_readers = self.info().ok()?.max_readers();
+        Some(max_readers.saturating_sub(self.active_readers().ok()?))
+    }
 }
 
 impl DatabaseEnv {
+    /// Creates a read-only transaction, retrying with backoff according to `policy` if the
+    /// database ran out of reader slots.
+    ///
+    /// MDBX takes the reader slot when the transaction begins, so this is where running out of
+    /// slots surfaces. Any other error is returned immediately.
+    pub fn tx_retry(&self, policy: RetryPolicy) -> Result<<Self as Database>::TX, DatabaseError> {
+        let mut backoff = policy.initial_backoff;
+        let mut retries = 0;
+        loop {
+            match self.tx() {
+                Err(err) if err.is_readers_full() && retries < policy.max_retries => {
+                    std::thread::sleep(backoff);
+                    backoff = (backoff * 2).min(policy.max_backoff);
+                    retries += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
     /// Opens the database at the specified path with the given `EnvKind`.
     ///
     /// It does not create the tables, for that call [`DatabaseEnv::create_tables`].
@@ -371,7 +444,7 @@ impl DatabaseEnv {
             ..Default::default()
         });
         // Configure more readers
-        inner_env.set_max_readers(DEFAULT_MAX_READERS);
+        inner_env.set_max_readers(args.max_readers.unwrap_or(DEFAULT_MAX_READERS));
         // This parameter sets the maximum size of the "reclaimed list", and the unit of measurement
         // is "pages". Reclaimed list is the list of freed pages that's populated during the
         // lifetime of DB transaction, and through which MDBX searches when it needs to insert new
@@ -435,9 +508,21 @@ impl DatabaseEnv {
             _lock_file,
         };
 
+        if let Some(expected) = args.expected_page_size {
+            let actual = env.page_size()?;
+            if actual != expected {
+                return Err(DatabaseError::PageSizeMismatch { expected, actual })
+            }
+        }
+
         Ok(env)
     }
 
+    /// Returns the page size of the database, in bytes.
+    pub fn page_size(&self) -> Result<usize, DatabaseError> {
+        Ok(self.inner.stat().map_err(|e| DatabaseError::Stats(e.into()))?.page_size() as usize)
+    }
+
     /// Enables metrics on the database.
     pub fn with_metrics(mut self) -> Self {
         self.metrics = Some(DatabaseEnvMetrics::new().into());
@@ -498,17 +583,18 @@ mod tests {
     use super::*;
     use crate::{
         tables::{
-            AccountsHistory, CanonicalHeaders, Headers, PlainAccountState, PlainStorageState,
+            AccountsHistory, CanonicalHeaders, HashedStorages, Headers, PlainAccountState,
+            PlainStorageState,
         },
         test_utils::*,
-        AccountChangeSets,
+        AccountChangeSets, RawDupSort, RawKey, RawTable,
     };
     use alloy_consensus::Header;
     use alloy_primitives::{Address, B256, U256};
     use reth_db_api::{
         cursor::{DbDupCursorRO, DbDupCursorRW, ReverseWalker, Walker},
         models::{AccountBeforeTx, ShardedKey},
-        table::{Encode, Table},
+        table::{Compress, Encode, Table},
     };
     use reth_libmdbx::Error;
     use reth_primitives::{Account, StorageEntry};
@@ -1112,6 +1198,188 @@ mod tests {
         assert_eq!(dup_cursor.next_dup_val(), Ok(Some(entry2)));
     }
 
+    #[test]
+    fn db_cursor_update_current() {
+        let db: Arc<DatabaseEnv> = create_test_db(DatabaseEnvKind::RW);
+        let tx = db.tx_mut().expect(ERROR_INIT_TX);
+
+        let mut cursor = tx.cursor_write::<PlainAccountState>().unwrap();
+        // Not positioned yet.
+        assert_eq!(
+            cursor.update_current(Account::default()),
+            Err(DatabaseError::CursorNotPositioned(PlainAccountState::NAME))
+        );
+
+        let mut keys = (0..10).map(|_| Address::random()).collect::<Vec<_>>();
+        keys.sort();
+        for key in &keys {
+            cursor.insert(*key, Account::default()).expect(ERROR_PUT);
+        }
+
+        // Transform every value while walking the table.
+        let mut entry = cursor.first().unwrap();
+        while let Some((_, account)) = entry {
+            cursor.update_current(Account { nonce: account.nonce + 1, ..account }).unwrap();
+            entry = cursor.next().unwrap();
+        }
+
+        let accounts = cursor.walk(None).unwrap().collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(
+            accounts,
+            keys.iter()
+                .map(|key| (*key, Account { nonce: 1, ..Default::default() }))
+                .collect::<Vec<_>>()
+        );
+
+        // Only the current duplicate value is replaced.
+        let mut dup_cursor = tx.cursor_dup_write::<PlainStorageState>().unwrap();
+        let key = Address::random();
+        let entry1 = StorageEntry { key: B256::with_last_byte(1), value: U256::from(1) };
+        let entry2 = StorageEntry { key: B256::with_last_byte(2), value: U256::from(2) };
+        dup_cursor.upsert(key, entry1).expect(ERROR_UPSERT);
+        dup_cursor.upsert(key, entry2).expect(ERROR_UPSERT);
+
+        assert_eq!(dup_cursor.seek_by_key_subkey(key, entry1.key), Ok(Some(entry1)));
+        let updated = StorageEntry { value: U256::from(3), ..entry1 };
+        dup_cursor.update_current(updated).unwrap();
+        assert_eq!(dup_cursor.current(), Ok(Some((key, updated))));
+        assert_eq!(
+            dup_cursor.walk_dup(Some(key), None).unwrap().collect::<Result<Vec<_>, _>>(),
+            Ok(vec![(key, updated), (key, entry2)])
+        );
+    }
+
+    #[test]
+    fn db_cursor_update_current_unpositioned() {
+        let db: Arc<DatabaseEnv> = create_test_db(DatabaseEnvKind::RW);
+        let key = Address::random();
+        let tx = db.tx_mut().expect(ERROR_INIT_TX);
+        tx.put::<PlainAccountState>(key, Account::default()).expect(ERROR_PUT);
+
+        // A fresh cursor over a non-empty table is not positioned either.
+        let mut cursor = tx.cursor_write::<PlainAccountState>().unwrap();
+        assert_eq!(
+            cursor.update_current(Account { nonce: 1, ..Default::default() }),
+            Err(DatabaseError::CursorNotPositioned(PlainAccountState::NAME))
+        );
+        assert_eq!(tx.get::<PlainAccountState>(key), Ok(Some(Account::default())));
+    }
+
+    #[test]
+    fn db_fold() {
+        let env = create_test_db(DatabaseEnvKind::RW);
+
+        let tx = env.tx_mut().expect(ERROR_INIT_TX);
+        for i in 1..=4 {
+            let account = Account { balance: U256::from(i * 10), ..Default::default() };
+            tx.put::<PlainAccountState>(Address::with_last_byte(i), account).expect(ERROR_PUT);
+        }
+        tx.commit().expect(ERROR_COMMIT);
+
+        let tx = env.tx().expect(ERROR_INIT_TX);
+        let total = tx
+            .fold::<PlainAccountState, _>(U256::ZERO, |total, _, account| total + account.balance)
+            .unwrap();
+        assert_eq!(total, U256::from(100));
+
+        // Entries are visited in key order.
+        let addresses = tx
+            .fold::<PlainAccountState, _>(Vec::new(), |mut addresses, address, _| {
+                addresses.push(address);
+                addresses
+            })
+            .unwrap();
+        assert_eq!(addresses, (1..=4).map(Address::with_last_byte).collect::<Vec<_>>());
+
+        // Folding over an empty table yields the initial value.
+        assert_eq!(tx.fold::<tables::Bytecodes, _>(7, |count, _, _| count + 1).unwrap(), 7);
+    }
+
+    #[test]
+    fn db_raw_table_scan() {
+        let env = create_test_db(DatabaseEnvKind::RW);
+
+        let accounts = (1..=3)
+            .map(|i| {
+                (Address::with_last_byte(i), Account { nonce: i as u64, ..Default::default() })
+            })
+            .collect::<Vec<_>>();
+        let address = Address::with_last_byte(1);
+        let entries = (1..=3)
+            .map(|i| StorageEntry { key: B256::with_last_byte(i), value: U256::from(i) })
+            .collect::<Vec<_>>();
+
+        let tx = env.tx_mut().expect(ERROR_INIT_TX);
+        for (address, account) in &accounts {
+            tx.put::<PlainAccountState>(*address, *account).expect(ERROR_PUT);
+        }
+        for entry in &entries {
+            tx.put::<PlainStorageState>(address, *entry).expect(ERROR_PUT);
+        }
+        tx.commit().expect(ERROR_COMMIT);
+
+        let tx = env.tx().expect(ERROR_INIT_TX);
+
+        // Raw rows hold the on-disk bytes and decode back to the typed rows.
+        let mut cursor = tx.cursor_read::<RawTable<PlainAccountState>>().unwrap();
+        let raw = cursor.walk(None).unwrap().collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(raw.len(), accounts.len());
+        for ((key, value), (address, account)) in raw.iter().zip(&accounts) {
+            assert_eq!(key.raw_key(), address.encode().as_ref());
+            assert_eq!(value.raw_value(), account.compress().as_slice());
+            assert_eq!((key.key().unwrap(), value.value().unwrap()), (*address, *account));
+        }
+
+        // Dupsort values keep their subkey prefix, in subkey order.
+        let mut cursor = tx.cursor_dup_read::<RawDupSort<PlainStorageState>>().unwrap();
+        let raw = cursor
+            .walk_dup(Some(RawKey::new(address)), None)
+            .unwrap()
+            .map(|row| row.map(|(_, value)| value.value().unwrap()))
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(raw, entries);
+    }
+
+    #[test]
+    fn db_walk_dup_rev() {
+        let db: Arc<DatabaseEnv> = create_test_db(DatabaseEnvKind::RW);
+        let tx = db.tx_mut().expect(ERROR_INIT_TX);
+
+        let key1 = Address::with_last_byte(1);
+        let key2 = Address::with_last_byte(2);
+        let key3 = Address::with_last_byte(3);
+        let entries = (1..=3)
+            .map(|i| StorageEntry { key: B256::with_last_byte(i), value: U256::from(i) })
+            .collect::<Vec<_>>();
+
+        let mut cursor = tx.cursor_dup_write::<PlainStorageState>().unwrap();
+        for entry in &entries {
+            cursor.upsert(key1, *entry).expect(ERROR_UPSERT);
+        }
+        cursor.upsert(key2, entries[0]).expect(ERROR_UPSERT);
+        cursor.upsert(key3, entries[1]).expect(ERROR_UPSERT);
+
+        // Walks the values of a single key only.
+        assert_eq!(
+            cursor.walk_dup_rev(key1).unwrap().collect::<Result<Vec<_>, _>>(),
+            Ok(entries.iter().rev().copied().collect())
+        );
+        assert_eq!(cursor.last_dup(key1), Ok(Some(entries[2])));
+
+        // Key without duplicates.
+        assert_eq!(
+            cursor.walk_dup_rev(key2).unwrap().collect::<Result<Vec<_>, _>>(),
+            Ok(vec![entries[0]])
+        );
+        assert_eq!(cursor.last_dup(key2), Ok(Some(entries[0])));
+
+        // Missing key.
+        let missing = Address::with_last_byte(4);
+        assert_eq!(cursor.walk_dup_rev(missing).unwrap().count(), 0);
+        assert_eq!(cursor.last_dup(missing), Ok(None));
+    }
+
     #[test]
     fn db_cursor_dupsort_append() {
         let db: Arc<DatabaseEnv> = create_test_db(DatabaseEnvKind::RW);
@@ -1207,6 +1475,106 @@ mod tests {
         assert_eq!(result, Some(value))
     }
 
+    #[test]
+    fn readers_full_error() {
+        let err = DatabaseError::InitTx(reth_libmdbx::Error::ReadersFull.into());
+        assert!(err.is_readers_full());
+        let err = DatabaseError::InitTx(reth_libmdbx::Error::Invalid.into());
+        assert!(!err.is_readers_full());
+    }
+
+    #[test]
+    fn db_max_readers() {
+        let path = TempDir::new().expect(ERROR_TEMPDIR).into_path();
+        let env = DatabaseEnv::open(
+            &path,
+            DatabaseEnvKind::RW,
+            DatabaseArguments::new(ClientVersion::default()).with_max_readers(Some(8)),
+        )
+        .expect(ERROR_DB_CREATION);
+
+        let available = env.available_readers().unwrap();
+        assert!(available >= 8);
+        assert!(available < DEFAULT_MAX_READERS as usize);
+
+        // Every read transaction takes a reader slot until the limit is reached.
+        let mut txs = Vec::new();
+        let err = loop {
+            match env.tx() {
+                Ok(tx) => txs.push(tx),
+                Err(err) => break err,
+            }
+        };
+        assert!(err.is_readers_full());
+        assert_eq!(txs.len(), available);
+        assert_eq!(env.available_readers(), Some(0));
+
+        // Finished read transactions give their slots back.
+        txs.truncate(3);
+        assert_eq!(env.available_readers(), Some(available - 3));
+        drop(txs);
+        assert_eq!(env.available_readers(), Some(available));
+        assert!(env.tx().is_ok());
+    }
+
+    #[test]
+    fn db_tx_retry() {
+        const POLICY: RetryPolicy = RetryPolicy {
+            max_retries: 3,
+            initial_backoff: Duration::from_millis(1),
+            max_backoff: Duration::from_millis(2),
+        };
+
+        let path = TempDir::new().expect(ERROR_TEMPDIR).into_path();
+        let env = DatabaseEnv::open(
+            &path,
+            DatabaseEnvKind::RW,
+            DatabaseArguments::new(ClientVersion::default()).with_max_readers(Some(8)),
+        )
+        .expect(ERROR_DB_CREATION);
+
+        let mut txs = Vec::new();
+        while let Ok(tx) = env.tx() {
+            txs.push(tx);
+        }
+        assert!(txs.len() >= 8);
+
+        // No reader finishes, so the retries are exhausted.
+        assert!(env.tx_retry(POLICY).unwrap_err().is_readers_full());
+
+        // A reader finishes while the retries are pending.
+        let policy = RetryPolicy { max_retries: 100, ..POLICY };
+        std::thread::scope(|scope| {
+            let retry = scope.spawn(|| env.tx_retry(policy));
+            std::thread::sleep(Duration::from_millis(20));
+            txs.pop();
+            assert!(retry.join().unwrap().is_ok());
+        });
+    }
+
+    #[test]
+    fn db_expected_page_size() {
+        let path = TempDir::new().expect(ERROR_TEMPDIR).into_path();
+        let page_size = create_test_db_with_path(DatabaseEnvKind::RW, &path).page_size().unwrap();
+
+        let args = DatabaseArguments::new(ClientVersion::default());
+        DatabaseEnv::open(
+            &path,
+            DatabaseEnvKind::RO,
+            args.clone().with_expected_page_size(Some(page_size)),
+        )
+        .expect(ERROR_DB_CREATION);
+
+        let expected = page_size * 2;
+        let err = DatabaseEnv::open(
+            &path,
+            DatabaseEnvKind::RO,
+            args.with_expected_page_size(Some(expected)),
+        )
+        .unwrap_err();
+        assert_eq!(err, DatabaseError::PageSizeMismatch { expected, actual: page_size });
+    }
+
     #[test]
     fn db_dup_sort() {
         let env = create_test_db(DatabaseEnvKind::RW);
@@ -1297,6 +1665,62 @@ mod tests {
         }
     }
 
+    #[test]
+    fn db_walk_grouped() {
+        let env = create_test_db(DatabaseEnvKind::RW);
+        let key1 = B256::with_last_byte(1);
+        let key2 = B256::with_last_byte(2);
+        let key3 = B256::with_last_byte(3);
+
+        let value0 = StorageEntry { key: B256::with_last_byte(0), value: U256::from(0) };
+        let value1 = StorageEntry { key: B256::with_last_byte(1), value: U256::from(1) };
+        let value2 = StorageEntry { key: B256::with_last_byte(2), value: U256::from(2) };
+
+        env.update(|tx| {
+            tx.put::<HashedStorages>(key1, value1).expect(ERROR_PUT);
+            tx.put::<HashedStorages>(key1, value0).expect(ERROR_PUT);
+            tx.put::<HashedStorages>(key2, value2).expect(ERROR_PUT);
+            tx.put::<HashedStorages>(key3, value0).expect(ERROR_PUT);
+            tx.put::<HashedStorages>(key3, value1).expect(ERROR_PUT);
+            tx.put::<HashedStorages>(key3, value2).expect(ERROR_PUT);
+        })
+        .unwrap();
+
+        let tx = env.tx().expect(ERROR_INIT_TX);
+        let mut cursor = tx.cursor_dup_read::<HashedStorages>().unwrap();
+
+        // Walk all groups from the start of the table.
+        let groups = cursor.walk_grouped(None).unwrap().collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(
+            groups,
+            vec![
+                (key1, vec![value0, value1]),
+                (key2, vec![value2]),
+                (key3, vec![value0, value1, value2])
+            ]
+        );
+
+        // Start from a key in the middle of the table.
+        let groups =
+            cursor.walk_grouped(Some(key2)).unwrap().collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(groups, vec![(key2, vec![value2]), (key3, vec![value0, value1, value2])]);
+
+        // Start past the last key.
+        let mut walker = cursor.walk_grouped(Some(B256::with_last_byte(4))).unwrap();
+        assert_eq!(walker.next(), None);
+
+        // Reuse the same buffer between groups.
+        let mut walker = cursor.walk_grouped(None).unwrap();
+        let mut values = Vec::with_capacity(3);
+        assert_eq!(walker.next_group_into(&mut values), Some(Ok(key1)));
+        assert_eq!(values, vec![value0, value1]);
+        assert_eq!(walker.next_group_into(&mut values), Some(Ok(key2)));
+        assert_eq!(values, vec![value2]);
+        assert_eq!(walker.next_group_into(&mut values), Some(Ok(key3)));
+        assert_eq!(values, vec![value0, value1, value2]);
+        assert_eq!(walker.next_group_into(&mut values), None);
+    }
+
     #[test]
     fn dup_value_with_same_subkey() {
         let env = create_test_db(DatabaseEnvKind::RW);
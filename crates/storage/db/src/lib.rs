@@ -20,6 +20,7 @@ pub mod lockfile;
 #[cfg(feature = "mdbx")]
 mod metrics;
 pub mod static_file;
+#[macro_use]
 pub mod tables;
 #[cfg(feature = "mdbx")]
 mod utils;
@@ -31,7 +32,9 @@ pub mod mdbx;
 pub use reth_storage_errors::db::{DatabaseError, DatabaseWriteOperation};
 pub use tables::*;
 #[cfg(feature = "mdbx")]
-pub use utils::is_database_empty;
+pub use utils::{
+    copy_dupsort_range, copy_range, export_tables, is_database_empty, TableExportSink,
+};
 
 #[cfg(feature = "mdbx")]
 pub use mdbx::{create_db, init_db, open_db, open_db_read_only, DatabaseEnv, DatabaseEnvKind};
@@ -159,6 +162,10 @@ pub mod test_utils {
         fn metadata(&self) -> DatabaseMetadataValue {
             self.db().metadata()
         }
+
+        fn available_readers(&self) -> Option<usize> {
+            self.db().available_readers()
+        }
     }
 
     /// Create `static_files` path for testing
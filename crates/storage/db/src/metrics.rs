@@ -212,6 +212,8 @@ pub(crate) enum Operation {
     CursorDeleteCurrent,
     /// Database cursor delete current duplicates operation.
     CursorDeleteCurrentDuplicates,
+    /// Database cursor update current operation.
+    CursorUpdateCurrent,
 }
 
 impl Operation {
@@ -227,6 +229,7 @@ impl Operation {
             Self::CursorAppendDup => "cursor-append-dup",
             Self::CursorDeleteCurrent => "cursor-delete-current",
             Self::CursorDeleteCurrentDuplicates => "cursor-delete-current-duplicates",
+            Self::CursorUpdateCurrent => "cursor-update-current",
         }
     }
 }
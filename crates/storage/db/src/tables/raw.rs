@@ -94,6 +94,10 @@ impl<K: Key> Encode for RawKey<K> {
     fn encode(self) -> Self::Encoded {
         self.key
     }
+
+    fn encode_to(self, buf: &mut Vec<u8>) {
+        buf.extend_from_slice(&self.key);
+    }
 }
 
 // Decode
@@ -1,6 +1,13 @@
 //! Utils crate for `db`.
 
-use std::path::Path;
+use crate::{RawDupSort, RawKey, RawTable, Tables};
+use reth_db_api::{
+    cursor::{DbCursorRO, DbCursorRW, DbDupCursorRW},
+    table::{DupSort, Table},
+    transaction::{DbTx, DbTxMut},
+    DatabaseError,
+};
+use std::{ops::RangeBounds, path::Path};
 
 /// Returns the default page size that can be used in this OS.
 pub(crate) fn default_page_size() -> usize {
@@ -32,9 +39,144 @@ pub fn is_database_empty<P: AsRef<Path>>(path: P) -> bool {
     }
 }
 
+/// Copies all rows of table `T` within `range` from `src_tx` into `dst_tx`.
+///
+/// Rows are copied in their raw encoded form, without decoding or re-compressing them, and are
+/// appended to the destination, so the destination table must not contain any keys greater than
+/// or equal to the start of the range.
+///
+/// Returns the number of copied rows.
+///
+/// For [`DupSort`] tables use [`copy_dupsort_range`], which preserves the subkey structure.
+pub fn copy_range<T: Table>(
+    src_tx: &impl DbTx,
+    dst_tx: &impl DbTxMut,
+    range: impl RangeBounds<T::Key>,
+) -> Result<usize, DatabaseError> {
+    let range = (
+        range.start_bound().cloned().map(RawKey::new),
+        range.end_bound().cloned().map(RawKey::new),
+    );
+    let mut src_cursor = src_tx.cursor_read::<RawTable<T>>()?;
+    let mut dst_cursor = dst_tx.cursor_write::<RawTable<T>>()?;
+
+    let mut copied = 0;
+    for row in src_cursor.walk_range(range)? {
+        let (key, value) = row?;
+        dst_cursor.append(key, value)?;
+        copied += 1;
+    }
+
+    Ok(copied)
+}
+
+/// Copies all entries of the [`DupSort`] table `T` with keys within `range` from `src_tx` into
+/// `dst_tx`.
+///
+/// Entries are copied in their raw encoded form and appended as duplicates of their key, so the
+/// destination keeps the same subkey ordering as the source. The destination table must not
+/// contain any keys greater than or equal to the start of the range.
+///
+/// Returns the number of copied entries.
+pub fn copy_dupsort_range<T: DupSort>(
+    src_tx: &impl DbTx,
+    dst_tx: &impl DbTxMut,
+    range: impl RangeBounds<T::Key>,
+) -> Result<usize, DatabaseError> {
+    let range = (
+        range.start_bound().cloned().map(RawKey::new),
+        range.end_bound().cloned().map(RawKey::new),
+    );
+    let mut src_cursor = src_tx.cursor_dup_read::<RawDupSort<T>>()?;
+    let mut dst_cursor = dst_tx.cursor_dup_write::<RawDupSort<T>>()?;
+
+    let mut copied = 0;
+    for row in src_cursor.walk_range(range)? {
+        let (key, value) = row?;
+        dst_cursor.append_dup(key, value)?;
+        copied += 1;
+    }
+
+    Ok(copied)
+}
+
+/// Receiver of the snapshot produced by [`export_tables`].
+pub trait TableExportSink {
+    /// Called once, before any row, with the exported tables and their row counts in export
+    /// order.
+    fn manifest(&mut self, manifest: &[(Tables, usize)]) -> Result<(), DatabaseError>;
+
+    /// Called for every row of `table`, in key order, with its raw encoded key and value.
+    fn row(&mut self, table: Tables, key: &[u8], value: &[u8]) -> Result<(), DatabaseError>;
+}
+
+/// Exports the tables with the given `names` from `tx` into `sink`.
+///
+/// The manifest is emitted first, followed by the rows of each table. Tables are exported in the
+/// order of [`Tables::ALL`] regardless of the order of `names`, and are read within the single
+/// transaction, so the export is a consistent snapshot across all of them. Rows are emitted in
+/// their raw encoded form, so they can be re-imported with a plain `put` into
+/// [`RawTable`] of the same table.
+pub fn export_tables(
+    tx: &impl DbTx,
+    names: &[&str],
+    sink: &mut impl TableExportSink,
+) -> Result<(), DatabaseError> {
+    let requested = names
+        .iter()
+        .map(|name| name.parse::<Tables>().map_err(DatabaseError::Other))
+        .collect::<Result<Vec<_>, _>>()?;
+    let tables =
+        Tables::ALL.iter().copied().filter(|table| requested.contains(table)).collect::<Vec<_>>();
+
+    let manifest = tables
+        .iter()
+        .map(|table| Ok((*table, tables_to_generic!(table, |T| tx.entries::<T>())?)))
+        .collect::<Result<Vec<_>, DatabaseError>>()?;
+    sink.manifest(&manifest)?;
+
+    for table in tables {
+        tables_to_generic!(table, |T| {
+            let mut cursor = tx.cursor_read::<RawTable<T>>()?;
+            for row in cursor.walk(None)? {
+                let (key, value) = row?;
+                sink.row(table, key.raw_key(), value.raw_value())?;
+            }
+        });
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{
+        tables::{CanonicalHeaders, PlainStorageState},
+        test_utils::create_test_rw_db,
+        Database, RawValue,
+    };
+    use alloy_primitives::{Address, B256, U256};
+    use reth_db_api::cursor::DbDupCursorRO;
+    use reth_primitives::StorageEntry;
+
+    #[derive(Default)]
+    struct VecSink {
+        manifest: Vec<(Tables, usize)>,
+        rows: Vec<(Tables, Vec<u8>, Vec<u8>)>,
+    }
+
+    impl TableExportSink for VecSink {
+        fn manifest(&mut self, manifest: &[(Tables, usize)]) -> Result<(), DatabaseError> {
+            self.manifest = manifest.to_vec();
+            Ok(())
+        }
+
+        fn row(&mut self, table: Tables, key: &[u8], value: &[u8]) -> Result<(), DatabaseError> {
+            self.rows.push((table, key.to_vec(), value.to_vec()));
+            Ok(())
+        }
+    }
 
     #[test]
     fn is_database_empty_false_if_db_path_is_a_file() {
@@ -44,4 +186,136 @@ mod tests {
 
         assert!(!result);
     }
+
+    #[test]
+    fn copy_range_subrange() {
+        let src = create_test_rw_db();
+        let dst = create_test_rw_db();
+
+        let tx = src.tx_mut().unwrap();
+        for block in 0..10u64 {
+            tx.put::<CanonicalHeaders>(block, B256::with_last_byte(block as u8)).unwrap();
+        }
+        tx.commit().unwrap();
+
+        let src_tx = src.tx().unwrap();
+        let dst_tx = dst.tx_mut().unwrap();
+        assert_eq!(copy_range::<CanonicalHeaders>(&src_tx, &dst_tx, 3..7).unwrap(), 4);
+        dst_tx.commit().unwrap();
+
+        let tx = dst.tx().unwrap();
+        let copied = tx
+            .cursor_read::<CanonicalHeaders>()
+            .unwrap()
+            .walk(None)
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(
+            copied,
+            (3..7u64).map(|block| (block, B256::with_last_byte(block as u8))).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn copy_dupsort_range_preserves_subkeys() {
+        let src = create_test_rw_db();
+        let dst = create_test_rw_db();
+
+        let addresses = [Address::with_last_byte(1), Address::with_last_byte(2)];
+        let entries = (0..3u8)
+            .map(|slot| StorageEntry { key: B256::with_last_byte(slot), value: U256::from(slot) })
+            .collect::<Vec<_>>();
+
+        let tx = src.tx_mut().unwrap();
+        for address in addresses {
+            for entry in &entries {
+                tx.put::<PlainStorageState>(address, *entry).unwrap();
+            }
+        }
+        tx.commit().unwrap();
+
+        let src_tx = src.tx().unwrap();
+        let dst_tx = dst.tx_mut().unwrap();
+        assert_eq!(
+            copy_dupsort_range::<PlainStorageState>(&src_tx, &dst_tx, addresses[1]..).unwrap(),
+            3
+        );
+        dst_tx.commit().unwrap();
+
+        let tx = dst.tx().unwrap();
+        let mut cursor = tx.cursor_dup_read::<PlainStorageState>().unwrap();
+        assert_eq!(cursor.seek_exact(addresses[0]).unwrap(), None);
+        let copied = cursor
+            .walk_dup(Some(addresses[1]), None)
+            .unwrap()
+            .map(|row| row.map(|(_, entry)| entry))
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(copied, entries);
+        assert_eq!(
+            cursor.seek_by_key_subkey(addresses[1], B256::with_last_byte(1)).unwrap(),
+            Some(entries[1])
+        );
+    }
+
+    #[test]
+    fn export_tables_reimport() {
+        let src = create_test_rw_db();
+        let dst = create_test_rw_db();
+
+        let address = Address::with_last_byte(1);
+        let entries = (0..3u8)
+            .map(|slot| StorageEntry { key: B256::with_last_byte(slot), value: U256::from(slot) })
+            .collect::<Vec<_>>();
+
+        let tx = src.tx_mut().unwrap();
+        for block in 0..5u64 {
+            tx.put::<CanonicalHeaders>(block, B256::with_last_byte(block as u8)).unwrap();
+        }
+        for entry in &entries {
+            tx.put::<PlainStorageState>(address, *entry).unwrap();
+        }
+        tx.commit().unwrap();
+
+        let mut sink = VecSink::default();
+        export_tables(
+            &src.tx().unwrap(),
+            &[PlainStorageState::NAME, CanonicalHeaders::NAME],
+            &mut sink,
+        )
+        .unwrap();
+        assert_eq!(
+            sink.manifest,
+            vec![(Tables::CanonicalHeaders, 5), (Tables::PlainStorageState, 3)]
+        );
+        assert_eq!(sink.rows.len(), 8);
+
+        let tx = dst.tx_mut().unwrap();
+        for (table, key, value) in sink.rows {
+            tables_to_generic!(table, |T| tx
+                .put::<RawTable<T>>(RawKey::from_vec(key), RawValue::from_vec(value))
+                .unwrap());
+        }
+        tx.commit().unwrap();
+
+        let tx = dst.tx().unwrap();
+        assert_eq!(
+            collect::<CanonicalHeaders>(&tx),
+            (0..5u64).map(|block| (block, B256::with_last_byte(block as u8))).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            collect::<PlainStorageState>(&tx),
+            entries.iter().map(|entry| (address, *entry)).collect::<Vec<_>>()
+        );
+
+        assert_eq!(
+            export_tables(&tx, &["Unknown"], &mut VecSink::default()),
+            Err(DatabaseError::Other("unknown table: \"Unknown\"".to_string()))
+        );
+    }
+
+    fn collect<T: Table>(tx: &impl DbTx) -> Vec<(T::Key, T::Value)> {
+        tx.cursor_read::<T>().unwrap().walk(None).unwrap().collect::<Result<_, _>>().unwrap()
+    }
 }
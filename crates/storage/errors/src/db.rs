@@ -45,11 +45,38 @@ pub enum DatabaseError {
     /// Failed to use the specified log level, as it's not available.
     #[display("log level {_0:?} is not available")]
     LogLevelUnavailable(LogLevel),
+    /// The page size of the database doesn't match the expected one.
+    #[display(
+        "database page size {actual} doesn't match the expected page size {expected}, \
+         the database was likely created on a system with a different page size"
+    )]
+    PageSizeMismatch {
+        /// The expected page size, in bytes.
+        expected: usize,
+        /// The page size of the opened database, in bytes.
+        actual: usize,
+    },
+    /// The cursor of the table is not positioned at an item.
+    #[display("cursor of table {_0} is not positioned at an item")]
+    CursorNotPositioned(&'static str),
     /// Other unspecified error.
     #[display("{_0}")]
     Other(String),
 }
 
+impl DatabaseError {
+    /// Error code returned by MDBX when all reader slots of the environment are in use.
+    pub const READERS_FULL_CODE: i32 = -30790;
+
+    /// Returns `true` if a read transaction couldn't be opened because the database ran out of
+    /// reader slots.
+    ///
+    /// This error is transient and the operation can be retried once other readers finish.
+    pub const fn is_readers_full(&self) -> bool {
+        matches!(self, Self::InitTx(DatabaseErrorInfo { code: Self::READERS_FULL_CODE, .. }))
+    }
+}
+
 impl core::error::Error for DatabaseError {
     fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
         match self {
@@ -125,6 +152,8 @@ pub enum DatabaseWriteOperation {
     CursorInsert,
     /// Append duplicate cursor.
     CursorAppendDup,
+    /// Update current cursor.
+    CursorUpdateCurrent,
     /// Put.
     Put,
 }
@@ -101,6 +101,14 @@ pub enum ProviderError {
     /// State is not available for the given block number because it is pruned.
     #[display("state at block #{_0} is pruned")]
     StateAtBlockPruned(BlockNumber),
+    /// The history index lags the changesets, so the state after a block can't be resolved.
+    #[display("history is indexed up to block #{indexed}, but changesets reach block #{executed}")]
+    HistoryNotIndexed {
+        /// The block the history is indexed up to.
+        indexed: BlockNumber,
+        /// The block the changesets are written up to.
+        executed: BlockNumber,
+    },
     /// Provider does not support this particular request.
     #[display("this provider does not support this request")]
     UnsupportedProvider,
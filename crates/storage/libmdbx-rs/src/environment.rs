@@ -182,6 +182,37 @@ impl Environment {
         }
     }
 
+    /// Retrieves the number of reader slots currently held by open read transactions.
+    ///
+    /// Unlike [`Info::num_readers`], which is the highest reader slot ever used, this walks the
+    /// reader table and only counts slots that are bound to a reader.
+    pub fn active_readers(&self) -> Result<usize> {
+        unsafe extern "C" fn count_reader(
+            ctx: *mut std::ffi::c_void,
+            _num: std::ffi::c_int,
+            _slot: std::ffi::c_int,
+            _pid: ffi::mdbx_pid_t,
+            _thread: ffi::mdbx_tid_t,
+            _txnid: u64,
+            _lag: u64,
+            _bytes_used: usize,
+            _bytes_retained: usize,
+        ) -> std::ffi::c_int {
+            *ctx.cast::<usize>() += 1;
+            ffi::MDBX_SUCCESS
+        }
+
+        let mut readers: usize = 0;
+        mdbx_result(unsafe {
+            ffi::mdbx_reader_list(
+                self.env_ptr(),
+                Some(count_reader),
+                ptr::from_mut(&mut readers).cast(),
+            )
+        })?;
+        Ok(readers)
+    }
+
     /// Retrieves the total number of pages on the freelist.
     ///
     /// Along with [`Environment::info()`], this can be used to calculate the exact number
@@ -142,6 +142,22 @@ fn test_info() {
     assert!(env.is_read_only().unwrap());
 }
 
+#[test]
+fn test_active_readers() {
+    let dir = tempdir().unwrap();
+    let env = Environment::builder().open(dir.path()).unwrap();
+    assert_eq!(env.active_readers().unwrap(), 0);
+
+    let txns = (0..3).map(|_| env.begin_ro_txn().unwrap()).collect::<Vec<_>>();
+    assert_eq!(env.active_readers().unwrap(), 3);
+    assert_eq!(env.info().unwrap().num_readers(), 3);
+
+    // Finished readers release their slots, but the highest slot used stays recorded.
+    drop(txns);
+    assert_eq!(env.active_readers().unwrap(), 0);
+    assert_eq!(env.info().unwrap().num_readers(), 3);
+}
+
 #[test]
 fn test_freelist() {
     let dir = tempdir().unwrap();
@@ -241,6 +241,10 @@ impl<N: ProviderNodeTypes> BlockNumReader for BlockchainProvider2<N> {
     fn block_number(&self, hash: B256) -> ProviderResult<Option<BlockNumber>> {
         self.consistent_provider()?.block_number(hash)
     }
+
+    fn recent_block_hashes(&self, count: u64) -> ProviderResult<Vec<(BlockNumber, B256)>> {
+        self.consistent_provider()?.recent_block_hashes(count)
+    }
 }
 
 impl<N: ProviderNodeTypes> BlockIdReader for BlockchainProvider2<N> {
@@ -1501,6 +1505,36 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_recent_block_hashes() -> eyre::Result<()> {
+        let mut rng = generators::rng();
+        let (provider, database_blocks, in_memory_blocks, _) = provider_with_random_blocks(
+            &mut rng,
+            TEST_BLOCKS_COUNT,
+            TEST_BLOCKS_COUNT,
+            BlockRangeParams::default(),
+        )?;
+        let blocks = [database_blocks, in_memory_blocks.clone()].concat();
+        let expected = |count: usize| {
+            blocks[blocks.len() - count..]
+                .iter()
+                .map(|block| (block.number, block.hash()))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(provider.recent_block_hashes(0)?, vec![]);
+        assert_eq!(provider.recent_block_hashes(3)?, expected(3));
+        // Spans both the database and the in-memory blocks.
+        assert_eq!(provider.recent_block_hashes(7)?, expected(7));
+        assert_eq!(provider.recent_block_hashes(256)?, expected(blocks.len()));
+
+        // Persisting the lowest in-memory block while the hashes are read doesn't hide it.
+        persist_block_after_db_tx_creation(provider.clone(), in_memory_blocks[0].number);
+        assert_eq!(provider.recent_block_hashes(7)?, expected(7));
+
+        Ok(())
+    }
+
     #[test]
     fn test_block_reader_id_ext_block_by_id() -> eyre::Result<()> {
         let mut rng = generators::rng();
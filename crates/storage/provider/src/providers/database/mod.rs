@@ -12,11 +12,15 @@ use alloy_eips::{
     eip4895::{Withdrawal, Withdrawals},
     BlockHashOrNumber,
 };
-use alloy_primitives::{Address, BlockHash, BlockNumber, TxHash, TxNumber, B256, U256};
+use alloy_primitives::{
+    map::HashMap, Address, BlockHash, BlockNumber, TxHash, TxNumber, B256, U256,
+};
 use core::fmt;
 use reth_chainspec::{ChainInfo, EthereumHardforks};
 use reth_db::{init_db, mdbx::DatabaseArguments, DatabaseEnv};
-use reth_db_api::{database::Database, models::StoredBlockBodyIndices};
+use reth_db_api::{
+    database::Database, database_metrics::DatabaseMetadata, models::StoredBlockBodyIndices,
+};
 use reth_errors::{RethError, RethResult};
 use reth_evm::ConfigureEvmEnv;
 use reth_node_types::{NodeTypesWithDB, TxTy};
@@ -28,6 +32,7 @@ use reth_prune_types::{PruneCheckpoint, PruneModes, PruneSegment};
 use reth_stages_types::{StageCheckpoint, StageId};
 use reth_storage_api::TryIntoHistoricalStateProvider;
 use reth_storage_errors::provider::ProviderResult;
+use reth_tasks::pool::BlockingTaskPool;
 use revm::primitives::{BlockEnv, CfgEnvWithHandlerCfg};
 use std::{
     ops::{RangeBounds, RangeInclusive},
@@ -112,6 +117,16 @@ impl<N: NodeTypesWithDB> ProviderFactory<N> {
         &self.db
     }
 
+    /// Returns the number of read-only providers that can still be opened concurrently, or `None`
+    /// if the database doesn't limit them.
+    ///
+    /// The limit is configured when the database is opened, see
+    /// [`DatabaseArguments::with_max_readers`]. Work that opens a provider per parallel task should
+    /// size its concurrency to stay below it.
+    pub fn available_readers(&self) -> Option<usize> {
+        self.db.available_readers()
+    }
+
     #[cfg(any(test, feature = "test-utils"))]
     /// Consumes Self and returns DB
     pub fn into_db(self) -> N::DB {
@@ -156,6 +171,26 @@ impl<N: ProviderNodeTypes> ProviderFactory<N> {
         ))
     }
 
+    /// Returns a provider with a created `DbTx` inside, like [`Self::provider`], but opens the
+    /// read transaction on the given [`BlockingTaskPool`].
+    ///
+    /// Opening a read transaction can briefly block, so async code running on the tokio runtime
+    /// should prefer this over [`Self::provider`]. Synchronous callers should keep using
+    /// [`Self::provider`], which avoids the overhead of dispatching to the pool.
+    ///
+    /// # Panics
+    ///
+    /// Resumes the panic if opening the transaction panicked on the pool.
+    pub async fn provider_async(
+        &self,
+        pool: &BlockingTaskPool,
+    ) -> ProviderResult<DatabaseProviderRO<N::DB, N>> {
+        let this = self.clone();
+        pool.spawn(move || this.provider())
+            .await
+            .unwrap_or_else(|err| std::panic::resume_unwind(err))
+    }
+
     /// Returns a provider with a created `DbTxMut` inside, which allows fetching and updating
     /// data from the database using different types of providers. Example: [`HeaderProvider`]
     /// [`BlockHashReader`].  This may fail if the inner read/write database transaction fails to
@@ -240,6 +275,13 @@ impl<N: ProviderNodeTypes> HeaderProvider for ProviderFactory<N> {
         self.provider()?.header(block_hash)
     }
 
+    fn headers_by_hash(
+        &self,
+        block_hashes: &[BlockHash],
+    ) -> ProviderResult<HashMap<BlockHash, Header>> {
+        self.provider()?.headers_by_hash(block_hashes)
+    }
+
     fn header_by_number(&self, num: BlockNumber) -> ProviderResult<Option<Header>> {
         self.static_file_provider.get_with_static_file_or_database(
             StaticFileSegment::Headers,
@@ -347,6 +389,10 @@ impl<N: ProviderNodeTypes> BlockNumReader for ProviderFactory<N> {
         self.provider()?.last_block_number()
     }
 
+    fn recent_block_hashes(&self, count: u64) -> ProviderResult<Vec<(BlockNumber, B256)>> {
+        self.provider()?.recent_block_hashes(count)
+    }
+
     fn block_number(&self, hash: B256) -> ProviderResult<Option<BlockNumber>> {
         self.provider()?.block_number(hash)
     }
@@ -462,6 +508,13 @@ impl<N: ProviderNodeTypes> TransactionsProvider for ProviderFactory<N> {
         self.provider()?.transaction_block(id)
     }
 
+    fn transaction_block_and_index(
+        &self,
+        hash: TxHash,
+    ) -> ProviderResult<Option<(BlockNumber, u64)>> {
+        self.provider()?.transaction_block_and_index(hash)
+    }
+
     fn transactions_by_block(
         &self,
         id: BlockHashOrNumber,
@@ -644,26 +697,65 @@ mod tests {
     use super::*;
     use crate::{
         providers::{StaticFileProvider, StaticFileWriter},
-        test_utils::{blocks::TEST_BLOCK, create_test_provider_factory, MockNodeTypesWithDB},
-        BlockHashReader, BlockNumReader, BlockWriter, DBProvider, HeaderSyncGapProvider,
-        StorageLocation, TransactionsProvider,
+        test_utils::{
+            blocks::TEST_BLOCK, create_test_provider_factory,
+            create_test_provider_factory_with_chain_spec, MockNodeTypesWithDB,
+        },
+        AccountExtReader, BlockHashReader, BlockNumReader, BlockWriter, DBProvider,
+        HeaderSyncGapProvider, PruneCheckpointReader, PruneCheckpointWriter, StageCheckpointWriter,
+        StateAvailability, StorageLocation, StorageReader, TransactionsProvider, TrieReader,
+        TrieWriter,
     };
-    use alloy_primitives::{TxNumber, B256, U256};
+    use alloy_primitives::{Address, TxNumber, B256, U256};
     use assert_matches::assert_matches;
     use rand::Rng;
-    use reth_chainspec::ChainSpecBuilder;
+    use reth_chainspec::{ChainSpecBuilder, EthereumHardfork, ForkCondition};
     use reth_db::{
         mdbx::DatabaseArguments,
+        models::{
+            storage_sharded_key::StorageShardedKey, AccountBeforeTx, BlockNumberAddress, ShardedKey,
+        },
         tables,
         test_utils::{create_test_static_files_dir, ERROR_TEMPDIR},
+        transaction::{DbTx, DbTxMut},
+        BlockNumberList,
     };
-    use reth_primitives::StaticFileSegment;
-    use reth_prune_types::{PruneMode, PruneModes};
+    use reth_primitives::{Account, StaticFileSegment, StorageEntry};
+    use reth_prune_types::{PruneCheckpoint, PruneMode, PruneModes, PruneSegment};
     use reth_storage_errors::provider::ProviderError;
-    use reth_testing_utils::generators::{self, random_block, random_header, BlockParams};
+    use reth_testing_utils::generators::{
+        self, random_block, random_block_range, random_header, BlockParams, BlockRangeParams,
+    };
+    use reth_trie::{StateRoot, StorageTrieEntry, StoredNibbles, StoredNibblesSubKey};
+    use reth_trie_db::DatabaseStateRoot;
     use std::{ops::RangeInclusive, sync::Arc};
     use tokio::sync::watch;
 
+    /// Inserts the blocks into the database and commits them.
+    fn insert_blocks(factory: &ProviderFactory<MockNodeTypesWithDB>, blocks: &[SealedBlock]) {
+        let provider = factory.provider_rw().unwrap();
+        for block in blocks {
+            provider
+                .insert_block(
+                    block.clone().try_seal_with_senders().unwrap(),
+                    StorageLocation::Database,
+                )
+                .unwrap();
+        }
+        provider.commit().unwrap();
+    }
+
+    /// Inserts random blocks of the range into the database and commits them.
+    fn insert_random_blocks(
+        factory: &ProviderFactory<MockNodeTypesWithDB>,
+        range: RangeInclusive<u64>,
+        params: BlockRangeParams,
+    ) -> Vec<SealedBlock> {
+        let blocks = random_block_range(&mut generators::rng(), range, params);
+        insert_blocks(factory, &blocks);
+        blocks
+    }
+
     #[test]
     fn common_history_provider() {
         let factory = create_test_provider_factory();
@@ -680,6 +772,407 @@ mod tests {
         assert_eq!(chain_info.best_hash, B256::ZERO);
     }
 
+    #[tokio::test]
+    async fn provider_async() {
+        let factory = create_test_provider_factory();
+        let pool = BlockingTaskPool::build().unwrap();
+        let provider = factory.provider_async(&pool).await.unwrap();
+
+        let chain_info = provider.chain_info().expect("should be ok");
+        assert_eq!(chain_info.best_number, 0);
+        assert_eq!(chain_info.best_hash, B256::ZERO);
+    }
+
+    #[test]
+    fn state_available_at() {
+        let factory = create_test_provider_factory();
+        let provider_rw = factory.provider_rw().unwrap();
+        provider_rw.save_stage_checkpoint(StageId::Finish, StageCheckpoint::new(10)).unwrap();
+        provider_rw.commit().unwrap();
+
+        let provider = factory.provider().unwrap();
+        assert_eq!(provider.state_available_at(0).unwrap(), StateAvailability::Full);
+        assert_eq!(provider.state_available_at(10).unwrap(), StateAvailability::Full);
+        assert_eq!(provider.state_available_at(11).unwrap(), StateAvailability::Future);
+        drop(provider);
+
+        // History is pruned up to and including block 5.
+        let provider_rw = factory.provider_rw().unwrap();
+        provider_rw
+            .save_prune_checkpoint(
+                PruneSegment::StorageHistory,
+                PruneCheckpoint {
+                    block_number: Some(5),
+                    tx_number: None,
+                    prune_mode: PruneMode::Before(6),
+                },
+            )
+            .unwrap();
+        provider_rw.commit().unwrap();
+
+        let provider = factory.provider().unwrap();
+        assert_eq!(provider.state_available_at(4).unwrap(), StateAvailability::Pruned);
+        assert_eq!(provider.state_available_at(5).unwrap(), StateAvailability::Full);
+        assert_eq!(provider.state_available_at(10).unwrap(), StateAvailability::Full);
+        assert_eq!(provider.state_available_at(11).unwrap(), StateAvailability::Future);
+    }
+
+    #[test]
+    fn get_prune_checkpoints() {
+        let factory = create_test_provider_factory();
+        assert_eq!(factory.get_prune_checkpoints().unwrap(), vec![]);
+
+        let checkpoint = PruneCheckpoint {
+            block_number: Some(10),
+            tx_number: Some(20),
+            prune_mode: PruneMode::Distance(64),
+        };
+        let provider_rw = factory.provider_rw().unwrap();
+        provider_rw.save_prune_checkpoint(PruneSegment::Receipts, checkpoint).unwrap();
+        provider_rw.commit().unwrap();
+
+        assert_eq!(
+            factory.get_prune_checkpoints().unwrap(),
+            vec![(PruneSegment::Receipts, checkpoint)]
+        );
+        assert_eq!(factory.get_prune_checkpoint(PruneSegment::Receipts).unwrap(), Some(checkpoint));
+        assert_eq!(factory.get_prune_checkpoint(PruneSegment::SenderRecovery).unwrap(), None);
+    }
+
+    #[test]
+    fn self_destructs_in_range() {
+        let factory = create_test_provider_factory();
+        let tx = factory.provider_rw().unwrap().into_tx();
+
+        let account = |nonce| Some(Account { nonce, ..Default::default() });
+        // Destroyed in block 2.
+        let destroyed = Address::with_last_byte(1);
+        // Created in block 1, destroyed in block 3 and re-created in block 4.
+        let recreated = Address::with_last_byte(2);
+        // Updated in block 2.
+        let updated = Address::with_last_byte(3);
+
+        for (block_number, address, info) in [
+            (1, destroyed, account(0)),
+            (1, recreated, None),
+            (2, destroyed, account(1)),
+            (2, updated, account(0)),
+            (3, recreated, account(0)),
+            (4, recreated, None),
+        ] {
+            tx.put::<tables::AccountChangeSets>(block_number, AccountBeforeTx { address, info })
+                .unwrap();
+        }
+        for (address, blocks) in
+            [(destroyed, vec![1, 2]), (recreated, vec![1, 3, 4]), (updated, vec![2])]
+        {
+            tx.put::<tables::AccountsHistory>(
+                ShardedKey::new(address, u64::MAX),
+                BlockNumberList::new(blocks).unwrap(),
+            )
+            .unwrap();
+        }
+        tx.put::<tables::PlainAccountState>(recreated, account(0).unwrap()).unwrap();
+        tx.put::<tables::PlainAccountState>(updated, account(1).unwrap()).unwrap();
+        tx.commit().unwrap();
+
+        let provider = factory.provider().unwrap();
+        assert_eq!(
+            provider.self_destructs_in_range(1..=4).unwrap(),
+            vec![(2, destroyed), (3, recreated)]
+        );
+        assert_eq!(
+            provider.self_destructs_in_range(1..=3).unwrap(),
+            vec![(2, destroyed), (3, recreated)]
+        );
+        assert_eq!(provider.self_destructs_in_range(1..=2).unwrap(), vec![(2, destroyed)]);
+        assert_eq!(provider.self_destructs_in_range(3..=3).unwrap(), vec![(3, recreated)]);
+        assert_eq!(provider.self_destructs_in_range(4..=4).unwrap(), vec![]);
+        assert_eq!(
+            provider.self_destructs_in_range(1..=u64::MAX).unwrap(),
+            vec![(2, destroyed), (3, recreated)]
+        );
+        drop(provider);
+
+        // Blocks 4 and later are not indexed yet, so the state after block 3 is unknown.
+        let provider_rw = factory.provider_rw().unwrap();
+        provider_rw.save_stage_checkpoint(StageId::Execution, StageCheckpoint::new(4)).unwrap();
+        provider_rw
+            .save_stage_checkpoint(StageId::IndexAccountHistory, StageCheckpoint::new(3))
+            .unwrap();
+        provider_rw.commit().unwrap();
+        let provider = factory.provider().unwrap();
+        assert_eq!(
+            provider.self_destructs_in_range(1..=3),
+            Err(ProviderError::HistoryNotIndexed { indexed: 3, executed: 4 })
+        );
+        assert_eq!(provider.self_destructs_in_range(3..=4).unwrap(), vec![(3, recreated)]);
+        drop(provider);
+
+        // The changesets of block 1 are pruned.
+        let provider_rw = factory.provider_rw().unwrap();
+        provider_rw
+            .save_stage_checkpoint(StageId::IndexAccountHistory, StageCheckpoint::new(4))
+            .unwrap();
+        provider_rw
+            .save_prune_checkpoint(
+                PruneSegment::AccountHistory,
+                PruneCheckpoint {
+                    block_number: Some(1),
+                    tx_number: None,
+                    prune_mode: PruneMode::Before(2),
+                },
+            )
+            .unwrap();
+        provider_rw.commit().unwrap();
+        let provider = factory.provider().unwrap();
+        assert_eq!(
+            provider.self_destructs_in_range(1..=4),
+            Err(ProviderError::StateAtBlockPruned(1))
+        );
+        assert_eq!(
+            provider.self_destructs_in_range(2..=4).unwrap(),
+            vec![(2, destroyed), (3, recreated)]
+        );
+    }
+
+    #[test]
+    fn storage_changes_for_account() {
+        let factory = create_test_provider_factory();
+        let tx = factory.provider_rw().unwrap().into_tx();
+
+        let address = Address::with_last_byte(1);
+        let other = Address::with_last_byte(2);
+        let slot = B256::with_last_byte;
+        let value = U256::from;
+
+        // Slot 1 is changed in blocks 1 and 2, slots 2 and 3 only in block 1.
+        for (block_number, address, key, value) in [
+            (1, address, slot(1), value(0)),
+            (1, address, slot(2), value(5)),
+            (1, address, slot(3), value(0)),
+            (1, other, slot(1), value(7)),
+            (2, address, slot(1), value(10)),
+        ] {
+            tx.put::<tables::StorageChangeSets>(
+                BlockNumberAddress((block_number, address)),
+                StorageEntry { key, value },
+            )
+            .unwrap();
+        }
+        for (address, key, blocks) in [
+            (address, slot(1), vec![1, 2]),
+            (address, slot(2), vec![1]),
+            (address, slot(3), vec![1]),
+            (other, slot(1), vec![1]),
+        ] {
+            tx.put::<tables::StoragesHistory>(
+                StorageShardedKey::new(address, key, u64::MAX),
+                BlockNumberList::new(blocks).unwrap(),
+            )
+            .unwrap();
+        }
+        // Slot 2 was cleared in block 1.
+        for (key, value) in [(slot(1), value(20)), (slot(3), value(30))] {
+            tx.put::<tables::PlainStorageState>(address, StorageEntry { key, value }).unwrap();
+        }
+        tx.commit().unwrap();
+
+        let provider = factory.provider().unwrap();
+        assert_eq!(
+            provider.storage_changes_for_account(address, 1).unwrap(),
+            vec![
+                (slot(1), value(0), value(10)),
+                (slot(2), value(5), value(0)),
+                (slot(3), value(0), value(30)),
+            ]
+        );
+        assert_eq!(
+            provider.storage_changes_for_account(address, 2).unwrap(),
+            vec![(slot(1), value(10), value(20))]
+        );
+        assert_eq!(provider.storage_changes_for_account(other, 2).unwrap(), vec![]);
+        assert_eq!(provider.storage_changes_for_account(address, 3).unwrap(), vec![]);
+        drop(provider);
+
+        // A change in the last block number has no following block.
+        let provider_rw = factory.provider_rw().unwrap();
+        provider_rw
+            .tx_ref()
+            .put::<tables::StorageChangeSets>(
+                BlockNumberAddress((u64::MAX, address)),
+                StorageEntry { key: slot(4), value: value(1) },
+            )
+            .unwrap();
+        provider_rw.commit().unwrap();
+        assert_eq!(
+            factory.provider().unwrap().storage_changes_for_account(address, u64::MAX).unwrap(),
+            vec![(slot(4), value(1), value(0))]
+        );
+
+        // Blocks 2 and later are not indexed yet, so the state after block 1 is unknown.
+        let provider_rw = factory.provider_rw().unwrap();
+        provider_rw.save_stage_checkpoint(StageId::Execution, StageCheckpoint::new(2)).unwrap();
+        provider_rw
+            .save_stage_checkpoint(StageId::IndexStorageHistory, StageCheckpoint::new(1))
+            .unwrap();
+        provider_rw.commit().unwrap();
+        let provider = factory.provider().unwrap();
+        assert_eq!(
+            provider.storage_changes_for_account(address, 1),
+            Err(ProviderError::HistoryNotIndexed { indexed: 1, executed: 2 })
+        );
+        assert_eq!(
+            provider.storage_changes_for_account(address, 2).unwrap(),
+            vec![(slot(1), value(10), value(20))]
+        );
+        drop(provider);
+
+        // The changesets of block 1 are pruned.
+        let provider_rw = factory.provider_rw().unwrap();
+        provider_rw
+            .save_stage_checkpoint(StageId::IndexStorageHistory, StageCheckpoint::new(2))
+            .unwrap();
+        provider_rw
+            .save_prune_checkpoint(
+                PruneSegment::StorageHistory,
+                PruneCheckpoint {
+                    block_number: Some(1),
+                    tx_number: None,
+                    prune_mode: PruneMode::Before(2),
+                },
+            )
+            .unwrap();
+        provider_rw.commit().unwrap();
+        let provider = factory.provider().unwrap();
+        assert_eq!(
+            provider.storage_changes_for_account(address, 1),
+            Err(ProviderError::StateAtBlockPruned(1))
+        );
+        assert_eq!(
+            provider.storage_changes_for_account(address, 2).unwrap(),
+            vec![(slot(1), value(10), value(20))]
+        );
+    }
+
+    #[test]
+    fn storage_history() {
+        let factory = create_test_provider_factory();
+        let tx = factory.provider_rw().unwrap().into_tx();
+
+        let address = Address::with_last_byte(1);
+        let slot = B256::with_last_byte(1);
+        let value = U256::from;
+
+        // The slot is set to 1 in block 1, 2 in block 3, rewritten with the same value in block 4,
+        // 3 in block 6 and 4 in block 8. It is also part of a history shard ending at block 4.
+        for (block_number, before) in
+            [(1, value(0)), (3, value(1)), (4, value(2)), (6, value(2)), (8, value(3))]
+        {
+            tx.put::<tables::StorageChangeSets>(
+                BlockNumberAddress((block_number, address)),
+                StorageEntry { key: slot, value: before },
+            )
+            .unwrap();
+        }
+        // Another slot of the same account changed in between.
+        tx.put::<tables::StorageChangeSets>(
+            BlockNumberAddress((5, address)),
+            StorageEntry { key: B256::with_last_byte(2), value: value(0) },
+        )
+        .unwrap();
+        for (highest_block_number, blocks) in [(4, vec![1, 3, 4]), (u64::MAX, vec![6, 8])] {
+            tx.put::<tables::StoragesHistory>(
+                StorageShardedKey::new(address, slot, highest_block_number),
+                BlockNumberList::new(blocks).unwrap(),
+            )
+            .unwrap();
+        }
+        tx.put::<tables::StoragesHistory>(
+            StorageShardedKey::new(address, B256::with_last_byte(2), u64::MAX),
+            BlockNumberList::new([5]).unwrap(),
+        )
+        .unwrap();
+        tx.put::<tables::PlainStorageState>(address, StorageEntry { key: slot, value: value(4) })
+            .unwrap();
+        tx.commit().unwrap();
+
+        let provider = factory.provider().unwrap();
+        assert_eq!(
+            provider.storage_history(address, slot, 0..=10).unwrap(),
+            vec![(1, value(1)), (3, value(2)), (6, value(3)), (8, value(4))]
+        );
+        assert_eq!(
+            provider.storage_history(address, slot, 2..=6).unwrap(),
+            vec![(3, value(2)), (6, value(3))]
+        );
+        assert_eq!(provider.storage_history(address, slot, 4..=5).unwrap(), vec![]);
+        assert_eq!(
+            provider.storage_history(address, B256::with_last_byte(3), 0..=10).unwrap(),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn walk_tries() {
+        let factory = create_test_provider_factory();
+        let provider = factory.provider_rw().unwrap();
+
+        let mut rng = generators::rng();
+        let hashed_addresses =
+            (0..500).map(|_| B256::from(rng.gen::<[u8; 32]>())).collect::<Vec<_>>();
+        for hashed_address in &hashed_addresses {
+            let account = Account { nonce: 1, ..Default::default() };
+            provider.tx_ref().put::<tables::HashedAccounts>(*hashed_address, account).unwrap();
+        }
+        let with_storage = hashed_addresses[0];
+        for _ in 0..300 {
+            let entry =
+                StorageEntry { key: B256::from(rng.gen::<[u8; 32]>()), value: U256::from(1) };
+            provider.tx_ref().put::<tables::HashedStorages>(with_storage, entry).unwrap();
+        }
+        let (root, updates) = StateRoot::from_tx(provider.tx_ref()).root_with_updates().unwrap();
+        provider.write_trie_updates(&updates).unwrap();
+        provider.commit().unwrap();
+        let updates = updates.into_sorted();
+
+        let provider = factory.provider().unwrap();
+        let account_nodes =
+            provider.walk_account_trie().unwrap().collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(account_nodes, updates.account_nodes_ref());
+
+        let storage_nodes = provider
+            .walk_storage_trie(with_storage)
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert!(!storage_nodes.is_empty());
+        assert_eq!(
+            storage_nodes,
+            updates.storage_tries_ref().get(&with_storage).unwrap().storage_nodes_ref()
+        );
+        assert_eq!(provider.walk_storage_trie(hashed_addresses[1]).unwrap().count(), 0);
+        drop(provider);
+
+        // Rewrite the tries from the walked nodes and reconstruct the root from them.
+        let provider = factory.provider_rw().unwrap();
+        provider.tx_ref().clear::<tables::AccountsTrie>().unwrap();
+        provider.tx_ref().clear::<tables::StoragesTrie>().unwrap();
+        for (path, node) in account_nodes {
+            provider.tx_ref().put::<tables::AccountsTrie>(StoredNibbles(path), node).unwrap();
+        }
+        for (path, node) in storage_nodes {
+            provider
+                .tx_ref()
+                .put::<tables::StoragesTrie>(
+                    with_storage,
+                    StorageTrieEntry { nibbles: StoredNibblesSubKey(path), node },
+                )
+                .unwrap();
+        }
+        assert_eq!(StateRoot::from_tx(provider.tx_ref()).root().unwrap(), root);
+    }
+
     #[test]
     fn provider_flow() {
         let factory = create_test_provider_factory();
@@ -752,6 +1245,231 @@ mod tests {
         }
     }
 
+    #[test]
+    fn transaction_block_and_index() {
+        let factory = create_test_provider_factory();
+        let blocks = insert_random_blocks(
+            &factory,
+            0..=3,
+            BlockRangeParams { tx_count: 1..4, ..Default::default() },
+        );
+
+        let provider = factory.provider().unwrap();
+        for block in &blocks {
+            for (index, transaction) in block.body.transactions.iter().enumerate() {
+                assert_eq!(
+                    provider.transaction_block_and_index(transaction.hash()),
+                    Ok(Some((block.number, index as u64)))
+                );
+            }
+        }
+        assert_eq!(provider.transaction_block_and_index(B256::random()), Ok(None));
+    }
+
+    #[test]
+    fn headers_by_hash() {
+        let factory = create_test_provider_factory();
+        let blocks = insert_random_blocks(&factory, 0..=3, BlockRangeParams::default());
+
+        let missing = B256::random();
+        let hashes = [blocks[1].hash(), missing, blocks[3].hash()];
+        let headers = factory.headers_by_hash(&hashes).unwrap();
+        assert_eq!(headers.len(), 2);
+        assert_eq!(headers.get(&blocks[1].hash()), Some(blocks[1].header.header()));
+        assert_eq!(headers.get(&blocks[3].hash()), Some(blocks[3].header.header()));
+        assert!(!headers.contains_key(&missing));
+    }
+
+    #[test]
+    fn is_canonical_hash() {
+        let factory = create_test_provider_factory();
+        let blocks = insert_random_blocks(&factory, 0..=3, BlockRangeParams::default());
+
+        // A sidechain block at height 2 that was reorged out but is still indexed by hash.
+        let provider = factory.provider_rw().unwrap();
+        let sidechain = random_block(
+            &mut generators::rng(),
+            2,
+            BlockParams { parent: Some(blocks[1].hash()), ..Default::default() },
+        );
+        provider.tx_ref().put::<tables::HeaderNumbers>(sidechain.hash(), 2).unwrap();
+        provider.commit().unwrap();
+
+        for block in &blocks {
+            assert!(factory.is_canonical_hash(block.hash()).unwrap());
+        }
+        assert!(!factory.is_canonical_hash(sidechain.hash()).unwrap());
+        assert!(!factory.is_canonical_hash(B256::random()).unwrap());
+    }
+
+    #[test]
+    fn sealed_block_by_number() {
+        let factory = create_test_provider_factory();
+        let blocks = insert_random_blocks(
+            &factory,
+            0..=3,
+            BlockRangeParams { tx_count: 1..4, ..Default::default() },
+        );
+
+        for block in &blocks {
+            let sealed = factory.sealed_block_by_number(block.number).unwrap().unwrap();
+            assert_eq!(sealed.hash(), block.hash());
+            assert_eq!(sealed.header.header().hash_slow(), block.hash());
+            assert_eq!(&sealed, block);
+        }
+        assert_eq!(factory.sealed_block_by_number(4).unwrap(), None);
+    }
+
+    #[test]
+    fn withdrawals_by_block_hash() {
+        let chain_spec = ChainSpecBuilder::mainnet()
+            .paris_activated()
+            .with_fork(EthereumHardfork::Shanghai, ForkCondition::Timestamp(1))
+            .build();
+        let factory = create_test_provider_factory_with_chain_spec(Arc::new(chain_spec));
+
+        let mut rng = generators::rng();
+        let mut blocks = Vec::new();
+        for (number, withdrawals_count) in [(0, None), (1, Some(2)), (2, Some(0))] {
+            let block = random_block(
+                &mut rng,
+                number,
+                BlockParams {
+                    parent: blocks.last().map(|block: &SealedBlock| block.hash()),
+                    withdrawals_count,
+                    ..Default::default()
+                },
+            );
+            let mut header = block.header.unseal();
+            header.timestamp = number;
+            blocks.push(SealedBlock::new(SealedHeader::seal(header), block.body));
+        }
+        insert_blocks(&factory, &blocks);
+
+        // Pre-Shanghai block.
+        assert_eq!(factory.withdrawals_by_block_hash(blocks[0].hash()).unwrap(), None);
+        assert!(factory.header(&blocks[0].hash()).unwrap().is_some());
+        // First Shanghai block.
+        assert_eq!(
+            factory.withdrawals_by_block_hash(blocks[1].hash()).unwrap(),
+            blocks[1].body.withdrawals
+        );
+        // Shanghai block without withdrawals.
+        assert_eq!(
+            factory.withdrawals_by_block_hash(blocks[2].hash()).unwrap(),
+            Some(Withdrawals::default())
+        );
+        // Unknown block.
+        let missing = B256::random();
+        assert_eq!(factory.withdrawals_by_block_hash(missing).unwrap(), None);
+        assert!(factory.header(&missing).unwrap().is_none());
+    }
+
+    #[test]
+    fn chain_info_is_consistent_under_concurrent_append() {
+        let factory = create_test_provider_factory();
+
+        let blocks = random_block_range(&mut generators::rng(), 0..=3, BlockRangeParams::default());
+        let append = |blocks: &[SealedBlock]| {
+            insert_blocks(&factory, blocks);
+            let provider = factory.provider_rw().unwrap();
+            let tip = blocks.last().unwrap().number;
+            provider.save_stage_checkpoint(StageId::Finish, StageCheckpoint::new(tip)).unwrap();
+            provider.commit().unwrap();
+        };
+        append(&blocks[..3]);
+
+        let snapshot = factory.provider().unwrap();
+        let tip = snapshot.chain_info().unwrap();
+        assert_eq!((tip.best_number, tip.best_hash), (2, blocks[2].hash()));
+
+        // A block appended after the read transaction was opened is not observed by it, neither
+        // through the tip number nor through the tip hash.
+        append(&blocks[3..]);
+        assert_eq!(snapshot.chain_info().unwrap(), tip);
+
+        let tip = factory.chain_info().unwrap();
+        assert_eq!((tip.best_number, tip.best_hash), (3, blocks[3].hash()));
+    }
+
+    #[test]
+    fn receipt_by_hash() {
+        let factory = create_test_provider_factory();
+        let blocks = insert_random_blocks(
+            &factory,
+            0..=2,
+            BlockRangeParams { tx_count: 2..4, ..Default::default() },
+        );
+
+        let provider = factory.provider_rw().unwrap();
+        let receipt = |tx_num: TxNumber| Receipt {
+            success: true,
+            cumulative_gas_used: tx_num,
+            ..Default::default()
+        };
+        let tx_count = blocks.iter().map(|block| block.body.transactions.len() as u64).sum();
+        for tx_num in 0..tx_count {
+            provider.tx_ref().put::<tables::Receipts>(tx_num, receipt(tx_num)).unwrap();
+        }
+        provider.commit().unwrap();
+
+        for (tx_num, transaction) in
+            blocks.iter().flat_map(|block| &block.body.transactions).enumerate()
+        {
+            assert_eq!(
+                factory.receipt_by_hash(transaction.hash()).unwrap(),
+                Some(receipt(tx_num as TxNumber))
+            );
+        }
+        assert_eq!(factory.receipt_by_hash(B256::random()).unwrap(), None);
+    }
+
+    #[test]
+    fn header_td_by_number() {
+        let factory = create_test_provider_factory();
+        let blocks = insert_random_blocks(&factory, 0..=3, BlockRangeParams::default());
+
+        let tip_td = blocks.iter().map(|block| block.difficulty).sum::<U256>();
+        assert_eq!(factory.header_td_by_number(0).unwrap(), Some(blocks[0].difficulty));
+        assert_eq!(factory.header_td_by_number(3).unwrap(), Some(tip_td));
+        assert_eq!(factory.header_td(&blocks[3].hash()).unwrap(), Some(tip_td));
+        assert_eq!(factory.header_td_by_number(4).unwrap(), None);
+    }
+
+    #[test]
+    fn recent_block_hashes() {
+        let factory = create_test_provider_factory();
+        let blocks = insert_random_blocks(&factory, 0..=5, BlockRangeParams::default());
+
+        let provider = factory.provider_rw().unwrap();
+        provider.save_stage_checkpoint(StageId::Finish, StageCheckpoint::new(5)).unwrap();
+        provider.commit().unwrap();
+
+        let expected = |range: RangeInclusive<usize>| {
+            blocks[range].iter().map(|block| (block.number, block.hash())).collect::<Vec<_>>()
+        };
+        assert_eq!(factory.recent_block_hashes(0).unwrap(), vec![]);
+        assert_eq!(factory.recent_block_hashes(1).unwrap(), expected(5..=5));
+        assert_eq!(factory.recent_block_hashes(3).unwrap(), expected(3..=5));
+        // Capped at genesis.
+        assert_eq!(factory.recent_block_hashes(256).unwrap(), expected(0..=5));
+
+        // A missing hash doesn't shift the numbers of the following blocks.
+        let provider = factory.provider_rw().unwrap();
+        provider.tx_ref().delete::<tables::CanonicalHeaders>(4, None).unwrap();
+        provider.commit().unwrap();
+        assert_eq!(
+            factory.recent_block_hashes(3).unwrap(),
+            [expected(3..=3), expected(5..=5)].concat()
+        );
+
+        // A tip at the end of the number space doesn't overflow.
+        let provider = factory.provider_rw().unwrap();
+        provider.save_stage_checkpoint(StageId::Finish, StageCheckpoint::new(u64::MAX)).unwrap();
+        provider.commit().unwrap();
+        assert_eq!(factory.recent_block_hashes(3).unwrap(), vec![]);
+    }
+
     #[test]
     fn take_block_transaction_range_recover_senders() {
         let factory = create_test_provider_factory();
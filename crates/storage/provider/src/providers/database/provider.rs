@@ -16,9 +16,10 @@ use crate::{
     HeaderSyncGapProvider, HistoricalStateProvider, HistoricalStateProviderRef, HistoryWriter,
     LatestStateProvider, LatestStateProviderRef, OriginalValuesKnown, ProviderError,
     PruneCheckpointReader, PruneCheckpointWriter, RevertsInit, StageCheckpointReader,
-    StateChangeWriter, StateProviderBox, StateReader, StateWriter, StaticFileProviderFactory,
-    StatsReader, StorageLocation, StorageReader, StorageTrieWriter, TransactionVariant,
-    TransactionsProvider, TransactionsProviderExt, TrieWriter, WithdrawalsProvider,
+    StateAvailability, StateChangeWriter, StateProviderBox, StateReader, StateWriter,
+    StaticFileProviderFactory, StatsReader, StorageLocation, StorageReader, StorageTrieWriter,
+    TransactionVariant, TransactionsProvider, TransactionsProviderExt, TrieReader, TrieWriter,
+    WithdrawalsProvider,
 };
 use alloy_consensus::Header;
 use alloy_eips::{
@@ -59,10 +60,11 @@ use reth_storage_api::{StateProvider, StorageChangeSetReader, TryIntoHistoricalS
 use reth_storage_errors::provider::{ProviderResult, RootMismatch};
 use reth_trie::{
     prefix_set::{PrefixSet, PrefixSetMut, TriePrefixSets},
+    trie_cursor::TrieCursor,
     updates::{StorageTrieUpdates, TrieUpdates},
-    HashedPostStateSorted, Nibbles, StateRoot, StoredNibbles,
+    BranchNodeCompact, HashedPostStateSorted, Nibbles, StateRoot, StoredNibbles,
 };
-use reth_trie_db::{DatabaseStateRoot, DatabaseStorageTrieCursor};
+use reth_trie_db::{DatabaseAccountTrieCursor, DatabaseStateRoot, DatabaseStorageTrieCursor};
 use revm::{
     db::states::{PlainStateReverts, PlainStorageChangeset, PlainStorageRevert, StateChangeset},
     primitives::{BlockEnv, CfgEnvWithHandlerCfg},
@@ -203,6 +205,38 @@ impl<TX: DbTx + 'static, N: NodeTypes> DatabaseProvider<TX, N> {
 
         Ok(Box::new(state_provider))
     }
+
+    /// Returns whether the state at the given block number can be provided.
+    ///
+    /// The state is [`StateAvailability::Pruned`] if the account or storage history needed to
+    /// revert the latest state to the block was pruned, and [`StateAvailability::Future`] if the
+    /// block is above the best block.
+    pub fn state_available_at(
+        &self,
+        block_number: BlockNumber,
+    ) -> ProviderResult<StateAvailability> {
+        let best_block_number = self.best_block_number()?;
+        if block_number > best_block_number {
+            return Ok(StateAvailability::Future)
+        }
+        if block_number == best_block_number {
+            return Ok(StateAvailability::Full)
+        }
+
+        // The state at the block is reverted with the changesets applied after it, see
+        // `history_by_block_hash`. Pruning removes history up to and including the checkpoint.
+        for segment in [PruneSegment::AccountHistory, PruneSegment::StorageHistory] {
+            if let Some(pruned_block_number) =
+                self.get_prune_checkpoint(segment)?.and_then(|checkpoint| checkpoint.block_number)
+            {
+                if pruned_block_number > block_number {
+                    return Ok(StateAvailability::Pruned)
+                }
+            }
+        }
+
+        Ok(StateAvailability::Full)
+    }
 }
 
 impl<TX, N: NodeTypes> StaticFileProviderFactory for DatabaseProvider<TX, N> {
@@ -929,6 +963,40 @@ impl<TX: DbTxMut + DbTx + 'static, N: NodeTypes> DatabaseProvider<TX, N> {
     }
 }
 
+impl<TX: DbTx, N: NodeTypes> DatabaseProvider<TX, N> {
+    /// Checks that the changesets of `range` and the history index of the blocks after it are
+    /// available for the history `segment` indexed by `index_stage`.
+    ///
+    /// The state after a block falls back to the plain state if the history index has no later
+    /// change, which is only correct if the index covers every changeset.
+    fn ensure_history_available(
+        &self,
+        segment: PruneSegment,
+        index_stage: StageId,
+        range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<()> {
+        // Pruning removes changesets and history up to and including the checkpoint.
+        if let Some(pruned_block_number) = self
+            .tx
+            .get::<tables::PruneCheckpoints>(segment)?
+            .and_then(|checkpoint| checkpoint.block_number)
+        {
+            if pruned_block_number >= *range.start() {
+                return Err(ProviderError::StateAtBlockPruned(*range.start()))
+            }
+        }
+
+        let executed =
+            self.get_stage_checkpoint(StageId::Execution)?.unwrap_or_default().block_number;
+        let indexed = self.get_stage_checkpoint(index_stage)?.unwrap_or_default().block_number;
+        if *range.end() < executed && indexed < executed {
+            return Err(ProviderError::HistoryNotIndexed { indexed, executed })
+        }
+
+        Ok(())
+    }
+}
+
 impl<TX: DbTx, N: NodeTypes> AccountReader for DatabaseProvider<TX, N> {
     fn basic_account(&self, address: Address) -> ProviderResult<Option<Account>> {
         Ok(self.tx.get::<tables::PlainAccountState>(address)?)
@@ -977,6 +1045,64 @@ impl<TX: DbTx, N: NodeTypes> AccountExtReader for DatabaseProvider<TX, N> {
 
         Ok(account_transitions)
     }
+
+    fn self_destructs_in_range(
+        &self,
+        range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<(BlockNumber, Address)>> {
+        self.ensure_history_available(
+            PruneSegment::AccountHistory,
+            StageId::IndexAccountHistory,
+            range.clone(),
+        )?;
+
+        let end = *range.end();
+        let mut changesets = self.tx.cursor_dup_read::<tables::AccountChangeSets>()?;
+
+        // The account state before each change of every account changed within the range.
+        let mut changes = BTreeMap::<Address, Vec<(BlockNumber, Option<Account>)>>::new();
+        for entry in changesets.walk_range(range)? {
+            let (block_number, AccountBeforeTx { address, info }) = entry?;
+            changes.entry(address).or_default().push((block_number, info));
+        }
+
+        let mut history = self.tx.cursor_read::<tables::AccountsHistory>()?;
+        let mut destroyed = Vec::new();
+        for (address, changes) in changes {
+            // The account state after the range is the state before the next change after the
+            // range, or the plain state if the account was not changed since.
+            let next_change = match end.checked_add(1) {
+                Some(next) => history
+                    .seek(ShardedKey::new(address, next))?
+                    .filter(|(key, _)| key.key == address)
+                    .and_then(|(_, chunk)| chunk.select(chunk.rank(end))),
+                // No block can follow a range ending at the last block number.
+                None => None,
+            };
+            let mut after = match next_change {
+                Some(block_number) => {
+                    changesets
+                        .seek_by_key_subkey(block_number, address)?
+                        .filter(|acc| acc.address == address)
+                        .ok_or(ProviderError::AccountChangesetNotFound { block_number, address })?
+                        .info
+                }
+                None => self.tx.get::<tables::PlainAccountState>(address)?,
+            };
+
+            // Walk the changes backwards, the state before a change is the state after the
+            // previous one.
+            for (block_number, before) in changes.into_iter().rev() {
+                if before.is_some() && after.is_none() {
+                    destroyed.push((block_number, address));
+                }
+                after = before;
+            }
+        }
+        destroyed.sort_unstable();
+
+        Ok(destroyed)
+    }
 }
 
 impl<TX: DbTx, N: NodeTypes> StorageChangeSetReader for DatabaseProvider<TX, N> {
@@ -1066,6 +1192,25 @@ impl<TX: DbTx + 'static, N: NodeTypes<ChainSpec: EthereumHardforks>> HeaderProvi
         }
     }
 
+    fn headers_by_hash(
+        &self,
+        block_hashes: &[BlockHash],
+    ) -> ProviderResult<alloy_primitives::map::HashMap<BlockHash, Header>> {
+        let mut header_numbers = self.tx.cursor_read::<tables::HeaderNumbers>()?;
+        let mut headers = alloy_primitives::map::HashMap::with_capacity_and_hasher(
+            block_hashes.len(),
+            Default::default(),
+        );
+        for block_hash in block_hashes {
+            if let Some((_, num)) = header_numbers.seek_exact(*block_hash)? {
+                if let Some(header) = self.header_by_number(num)? {
+                    headers.insert(*block_hash, header);
+                }
+            }
+        }
+        Ok(headers)
+    }
+
     fn header_by_number(&self, num: BlockNumber) -> ProviderResult<Option<Header>> {
         self.static_file_provider.get_with_static_file_or_database(
             StaticFileSegment::Headers,
@@ -1538,6 +1683,18 @@ impl<TX: DbTx + 'static, N: NodeTypesForProvider> TransactionsProvider for Datab
         Ok(cursor.seek(id)?.map(|(_, bn)| bn))
     }
 
+    fn transaction_block_and_index(
+        &self,
+        hash: TxHash,
+    ) -> ProviderResult<Option<(BlockNumber, u64)>> {
+        let Some(transaction_id) = self.transaction_id(hash)? else { return Ok(None) };
+        let Some(block_number) = self.transaction_block(transaction_id)? else { return Ok(None) };
+        let Some(block_body) = self.block_body_indices(block_number)? else { return Ok(None) };
+
+        // NOTE: `transaction_id` is always `>=` the block's first index
+        Ok(Some((block_number, transaction_id - block_body.first_tx_num())))
+    }
+
     fn transactions_by_block(
         &self,
         id: BlockHashOrNumber,
@@ -1865,6 +2022,131 @@ impl<TX: DbTx + 'static, N: NodeTypes> StorageReader for DatabaseProvider<TX, N>
 
         Ok(storage_changeset_lists)
     }
+
+    fn storage_changes_for_account(
+        &self,
+        address: Address,
+        block_number: BlockNumber,
+    ) -> ProviderResult<Vec<(B256, U256, U256)>> {
+        self.ensure_history_available(
+            PruneSegment::StorageHistory,
+            StageId::IndexStorageHistory,
+            block_number..=block_number,
+        )?;
+
+        let mut changesets = self.tx.cursor_dup_read::<tables::StorageChangeSets>()?;
+        let changes = changesets
+            .walk_dup(Some(BlockNumberAddress((block_number, address))), None)?
+            .map(|entry| entry.map(|(_, entry)| entry))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut history = self.tx.cursor_read::<tables::StoragesHistory>()?;
+        let mut plain_storage = self.tx.cursor_dup_read::<tables::PlainStorageState>()?;
+        let mut storage_changes = Vec::with_capacity(changes.len());
+        for StorageEntry { key, value: old } in changes {
+            // The slot value after the block is the value before the next change after the
+            // block, or the plain state value if the slot was not changed since.
+            let next_change = match block_number.checked_add(1) {
+                Some(next) => history
+                    .seek(StorageShardedKey::new(address, key, next))?
+                    .filter(|(sharded_key, _)| {
+                        sharded_key.address == address && sharded_key.sharded_key.key == key
+                    })
+                    .and_then(|(_, chunk)| chunk.select(chunk.rank(block_number))),
+                // No block can follow the last block number.
+                None => None,
+            };
+            let new = match next_change {
+                Some(next_block_number) => {
+                    changesets
+                        .seek_by_key_subkey(BlockNumberAddress((next_block_number, address)), key)?
+                        .filter(|entry| entry.key == key)
+                        .ok_or_else(|| ProviderError::StorageChangesetNotFound {
+                            block_number: next_block_number,
+                            address,
+                            storage_key: Box::new(key),
+                        })?
+                        .value
+                }
+                None => plain_storage
+                    .seek_by_key_subkey(address, key)?
+                    .filter(|entry| entry.key == key)
+                    .map(|entry| entry.value)
+                    .unwrap_or_default(),
+            };
+            storage_changes.push((key, old, new));
+        }
+
+        Ok(storage_changes)
+    }
+
+    fn storage_history(
+        &self,
+        address: Address,
+        slot: B256,
+        range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<(BlockNumber, U256)>> {
+        let (start, end) = (*range.start(), *range.end());
+
+        // Blocks changing the slot within the range, and the first one changing it after the
+        // range.
+        let mut change_blocks = Vec::new();
+        let mut next_change = None;
+        let mut history = self.tx.cursor_read::<tables::StoragesHistory>()?;
+        'shards: for entry in history.walk(Some(StorageShardedKey::new(address, slot, start)))? {
+            let (sharded_key, chunk) = entry?;
+            if sharded_key.address != address || sharded_key.sharded_key.key != slot {
+                break
+            }
+            for block_number in chunk.iter().skip_while(|block_number| *block_number < start) {
+                if block_number > end {
+                    next_change = Some(block_number);
+                    break 'shards
+                }
+                change_blocks.push(block_number);
+            }
+        }
+
+        let mut changesets = self.tx.cursor_dup_read::<tables::StorageChangeSets>()?;
+        let mut value_before = |block_number| -> ProviderResult<U256> {
+            changesets
+                .seek_by_key_subkey(BlockNumberAddress((block_number, address)), slot)?
+                .filter(|entry| entry.key == slot)
+                .map(|entry| entry.value)
+                .ok_or_else(|| ProviderError::StorageChangesetNotFound {
+                    block_number,
+                    address,
+                    storage_key: Box::new(slot),
+                })
+        };
+
+        // The slot value after the last change is the value before the next change after the
+        // range, or the plain state value if the slot was not changed since.
+        let mut after = match next_change {
+            Some(block_number) => value_before(block_number)?,
+            None => self
+                .tx
+                .cursor_dup_read::<tables::PlainStorageState>()?
+                .seek_by_key_subkey(address, slot)?
+                .filter(|entry| entry.key == slot)
+                .map(|entry| entry.value)
+                .unwrap_or_default(),
+        };
+
+        // Walk the changes backwards, the value before a change is the value after the previous
+        // one.
+        let mut values = Vec::with_capacity(change_blocks.len());
+        for block_number in change_blocks.into_iter().rev() {
+            let before = value_before(block_number)?;
+            if before != after {
+                values.push((block_number, after));
+            }
+            after = before;
+        }
+        values.reverse();
+
+        Ok(values)
+    }
 }
 
 impl<TX: DbTxMut + DbTx + 'static, N: NodeTypesForProvider> StateChangeWriter
@@ -2246,6 +2528,37 @@ impl<TX: DbTxMut + DbTx + 'static, N: NodeTypesForProvider> StateChangeWriter
     }
 }
 
+impl<TX: DbTx + 'static, N: NodeTypes> TrieReader for DatabaseProvider<TX, N> {
+    fn walk_account_trie(
+        &self,
+    ) -> ProviderResult<impl Iterator<Item = ProviderResult<(Nibbles, BranchNodeCompact)>>> {
+        let cursor = self.tx.cursor_read::<tables::AccountsTrie>()?;
+        Ok(walk_trie(DatabaseAccountTrieCursor::new(cursor)))
+    }
+
+    fn walk_storage_trie(
+        &self,
+        hashed_address: B256,
+    ) -> ProviderResult<impl Iterator<Item = ProviderResult<(Nibbles, BranchNodeCompact)>>> {
+        let cursor = self.tx.cursor_dup_read::<tables::StoragesTrie>()?;
+        Ok(walk_trie(DatabaseStorageTrieCursor::new(cursor, hashed_address)))
+    }
+}
+
+/// Returns an iterator that owns the trie cursor and walks all nodes of the trie in path order.
+fn walk_trie(
+    mut cursor: impl TrieCursor,
+) -> impl Iterator<Item = ProviderResult<(Nibbles, BranchNodeCompact)>> {
+    let mut next = Some(cursor.seek(Nibbles::default()));
+    std::iter::from_fn(move || {
+        let entry = next.take()?.transpose()?;
+        if entry.is_ok() {
+            next = Some(cursor.next());
+        }
+        Some(entry.map_err(Into::into))
+    })
+}
+
 impl<TX: DbTxMut + DbTx + 'static, N: NodeTypes> TrieWriter for DatabaseProvider<TX, N> {
     /// Writes trie updates. Returns the number of entries modified.
     fn write_trie_updates(&self, trie_updates: &TrieUpdates) -> ProviderResult<usize> {
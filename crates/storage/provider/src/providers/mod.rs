@@ -52,8 +52,8 @@ pub use static_file::{
 
 mod state;
 pub use state::{
-    historical::{HistoricalStateProvider, HistoricalStateProviderRef},
-    latest::{LatestStateProvider, LatestStateProviderRef},
+    historical::{HistoricalStateProvider, HistoricalStateProviderRef, StateAvailability},
+    latest::{AccountsPage, LatestStateProvider, LatestStateProviderRef},
 };
 
 mod bundle_state_provider;
@@ -313,6 +313,12 @@ impl<N: ProviderNodeTypes> BlockNumReader for BlockchainProvider<N> {
     fn block_number(&self, hash: B256) -> ProviderResult<Option<BlockNumber>> {
         self.database.block_number(hash)
     }
+
+    fn recent_block_hashes(&self, count: u64) -> ProviderResult<Vec<(BlockNumber, B256)>> {
+        // Canonical blocks are committed to the database together with the pipeline checkpoints,
+        // so the tip and the hashes can be read from a single database transaction.
+        self.database.recent_block_hashes(count)
+    }
 }
 
 impl<N: ProviderNodeTypes> BlockIdReader for BlockchainProvider<N> {
@@ -3,10 +3,7 @@ use crate::{
     ProviderError, StateProvider, StateRootProvider,
 };
 use alloy_eips::merge::EPOCH_SLOTS;
-use alloy_primitives::{
-    map::{HashMap, HashSet},
-    Address, BlockNumber, Bytes, StorageKey, StorageValue, B256,
-};
+use alloy_primitives::{map::HashMap, Address, BlockNumber, Bytes, StorageKey, StorageValue, B256};
 use reth_db::{tables, BlockNumberList};
 use reth_db_api::{
     cursor::{DbCursorRO, DbDupCursorRO},
@@ -21,7 +18,8 @@ use reth_trie::{
     proof::{Proof, StorageProof},
     updates::TrieUpdates,
     witness::TrieWitness,
-    AccountProof, HashedPostState, HashedStorage, MultiProof, StateRoot, StorageRoot, TrieInput,
+    AccountProof, HashedPostState, HashedStorage, MultiProof, ProofTargets, StateRoot,
+    StorageMultiProof, StorageRoot, TrieInput,
 };
 use reth_trie_db::{
     DatabaseHashedPostState, DatabaseHashedStorage, DatabaseProof, DatabaseStateRoot,
@@ -341,6 +339,18 @@ impl<Provider: DBProvider + BlockNumReader> StorageRootProvider
         StorageProof::overlay_storage_proof(self.tx(), address, slot, revert_storage)
             .map_err(Into::<ProviderError>::into)
     }
+
+    fn storage_multiproof(
+        &self,
+        address: Address,
+        slots: &[B256],
+        hashed_storage: HashedStorage,
+    ) -> ProviderResult<StorageMultiProof> {
+        let mut revert_storage = self.revert_storage(address)?;
+        revert_storage.extend(&hashed_storage);
+        StorageProof::overlay_storage_multiproof(self.tx(), address, slots, revert_storage)
+            .map_err(Into::<ProviderError>::into)
+    }
 }
 
 impl<Provider: DBProvider + BlockNumReader> StateProofProvider
@@ -361,7 +371,7 @@ impl<Provider: DBProvider + BlockNumReader> StateProofProvider
     fn multiproof(
         &self,
         mut input: TrieInput,
-        targets: HashMap<B256, HashSet<B256>>,
+        targets: ProofTargets,
     ) -> ProviderResult<MultiProof> {
         input.prepend(self.revert_state()?);
         Proof::overlay_multiproof(self.tx(), input, targets).map_err(Into::<ProviderError>::into)
@@ -494,6 +504,18 @@ impl LowestAvailableBlocks {
     }
 }
 
+/// Whether the state at a block can be provided.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StateAvailability {
+    /// The state at the block is available.
+    Full,
+    /// The state at the block can't be reconstructed, because the account or storage history
+    /// required to revert to it was pruned.
+    Pruned,
+    /// The block is above the tip.
+    Future,
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
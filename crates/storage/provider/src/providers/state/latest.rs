@@ -2,12 +2,14 @@ use crate::{
     providers::state::macros::delegate_provider_impls, AccountReader, BlockHashReader,
     StateProvider, StateRootProvider,
 };
-use alloy_primitives::{
-    map::{HashMap, HashSet},
-    Address, BlockNumber, Bytes, StorageKey, StorageValue, B256,
+use alloy_consensus::constants::KECCAK_EMPTY;
+use alloy_primitives::{map::HashMap, Address, BlockNumber, Bytes, StorageKey, StorageValue, B256};
+use reth_db::{tables, RawKey, RawTable};
+use reth_db_api::{
+    cursor::{DbCursorRO, DbDupCursorRO},
+    transaction::DbTx,
+    DatabaseError,
 };
-use reth_db::tables;
-use reth_db_api::{cursor::DbDupCursorRO, transaction::DbTx};
 use reth_primitives::{Account, Bytecode};
 use reth_storage_api::{DBProvider, StateProofProvider, StorageRootProvider};
 use reth_storage_errors::provider::{ProviderError, ProviderResult};
@@ -15,13 +17,19 @@ use reth_trie::{
     proof::{Proof, StorageProof},
     updates::TrieUpdates,
     witness::TrieWitness,
-    AccountProof, HashedPostState, HashedStorage, MultiProof, StateRoot, StorageRoot, TrieInput,
+    AccountProof, HashedPostState, HashedStorage, MultiProof, ProofTargets, StateRoot,
+    StorageMultiProof, StorageRoot, TrieInput,
 };
 use reth_trie_db::{
     DatabaseProof, DatabaseStateRoot, DatabaseStorageProof, DatabaseStorageRoot,
     DatabaseTrieWitness,
 };
 
+/// A page of plain state accounts, alongside the address to resume from to read the next page.
+///
+/// See [`LatestStateProviderRef::walk_accounts_from`].
+pub type AccountsPage = (Vec<(Address, Account)>, Option<Address>);
+
 /// State provider over latest state that takes tx reference.
 ///
 /// Wraps a [`DBProvider`] to get access to database.
@@ -37,6 +45,103 @@ impl<'b, Provider: DBProvider> LatestStateProviderRef<'b, Provider> {
     fn tx(&self) -> &Provider::Tx {
         self.0.tx_ref()
     }
+
+    /// Returns an iterator over the address and code hash of every contract account, i.e. every
+    /// account in the plain state with a code hash other than [`KECCAK_EMPTY`].
+    ///
+    /// Accounts are filtered while the table is walked, so the plain state is never collected.
+    pub fn walk_contract_accounts(
+        &self,
+    ) -> ProviderResult<impl Iterator<Item = ProviderResult<(Address, B256)>>> {
+        let mut cursor = self.tx().cursor_read::<tables::PlainAccountState>()?;
+        let start = cursor.first();
+        Ok(walk_owned(cursor, start, DbCursorRO::next).filter_map(|entry| match entry {
+            Ok((address, account)) => account
+                .bytecode_hash
+                .filter(|code_hash| *code_hash != KECCAK_EMPTY)
+                .map(|code_hash| Ok((address, code_hash))),
+            Err(err) => Some(Err(err.into())),
+        }))
+    }
+
+    /// Returns an iterator over the hashes of all bytecodes in the database, in ascending order.
+    ///
+    /// The bytecodes themselves are never decoded.
+    pub fn all_code_hashes(&self) -> ProviderResult<impl Iterator<Item = ProviderResult<B256>>> {
+        let mut cursor = self.tx().cursor_read::<RawTable<tables::Bytecodes>>()?;
+        let start = cursor.first();
+        Ok(walk_owned(cursor, start, DbCursorRO::next)
+            .map(|entry| Ok(entry.and_then(|(code_hash, _)| code_hash.key())?)))
+    }
+
+    /// Returns up to `limit` accounts of the plain state in ascending address order, starting at
+    /// the `from` address (inclusive) or the first account if `from` is `None`.
+    ///
+    /// The second element of the returned tuple is the address to resume from to read the next
+    /// page, or `None` if the returned page contains the last account.
+    pub fn walk_accounts_from(
+        &self,
+        from: Option<Address>,
+        limit: usize,
+    ) -> ProviderResult<AccountsPage> {
+        let mut cursor = self.tx().cursor_read::<tables::PlainAccountState>()?;
+        let start = match from {
+            Some(address) => cursor.seek(address),
+            None => cursor.first(),
+        };
+        let mut accounts = walk_owned(cursor, start, DbCursorRO::next);
+        let page = accounts.by_ref().take(limit).collect::<Result<Vec<_>, _>>()?;
+        let next = accounts.next().transpose()?.map(|(address, _)| address);
+        Ok((page, next))
+    }
+
+    /// Returns an iterator over all storage slots of the account and their values, in ascending
+    /// slot order.
+    ///
+    /// The iterator is empty if the account has no storage.
+    pub fn storage_entries(
+        &self,
+        address: Address,
+    ) -> ProviderResult<impl Iterator<Item = ProviderResult<(StorageKey, StorageValue)>>> {
+        let mut cursor = self.tx().cursor_dup_read::<tables::PlainStorageState>()?;
+        let start = cursor.seek_exact(address).map(|entry| entry.map(|(_, entry)| entry));
+        Ok(walk_owned(cursor, start, DbDupCursorRO::next_dup_val)
+            .map(|entry| Ok(entry.map(|entry| (entry.key, entry.value))?)))
+    }
+
+    /// Returns the number of accounts in the plain state.
+    ///
+    /// The count is read from the table statistics, so the table is not walked.
+    pub fn account_count(&self) -> ProviderResult<usize> {
+        Ok(self.tx().entries::<tables::PlainAccountState>()?)
+    }
+
+    /// Returns the number of storage slots across all accounts in the plain state.
+    ///
+    /// The count is read from the table statistics, so the table is not walked.
+    pub fn storage_slot_count(&self) -> ProviderResult<usize> {
+        Ok(self.tx().entries::<tables::PlainStorageState>()?)
+    }
+}
+
+/// Returns an iterator that owns the cursor, yields `start` and then advances the cursor with
+/// `next` until it is exhausted or fails.
+///
+/// Unlike [`Walker`](reth_db_api::cursor::Walker), the iterator does not borrow the cursor and
+/// can therefore be returned from the function that opened it.
+fn walk_owned<C, T>(
+    mut cursor: C,
+    start: Result<Option<T>, DatabaseError>,
+    mut next: impl FnMut(&mut C) -> Result<Option<T>, DatabaseError>,
+) -> impl Iterator<Item = Result<T, DatabaseError>> {
+    let mut entry = start.transpose();
+    std::iter::from_fn(move || {
+        let current = entry.take()?;
+        if current.is_ok() {
+            entry = next(&mut cursor).transpose();
+        }
+        Some(current)
+    })
 }
 
 impl<Provider: DBProvider> AccountReader for LatestStateProviderRef<'_, Provider> {
@@ -108,6 +213,16 @@ impl<Provider: DBProvider> StorageRootProvider for LatestStateProviderRef<'_, Pr
         StorageProof::overlay_storage_proof(self.tx(), address, slot, hashed_storage)
             .map_err(Into::<ProviderError>::into)
     }
+
+    fn storage_multiproof(
+        &self,
+        address: Address,
+        slots: &[B256],
+        hashed_storage: HashedStorage,
+    ) -> ProviderResult<StorageMultiProof> {
+        StorageProof::overlay_storage_multiproof(self.tx(), address, slots, hashed_storage)
+            .map_err(Into::<ProviderError>::into)
+    }
 }
 
 impl<Provider: DBProvider> StateProofProvider for LatestStateProviderRef<'_, Provider> {
@@ -121,11 +236,7 @@ impl<Provider: DBProvider> StateProofProvider for LatestStateProviderRef<'_, Pro
             .map_err(Into::<ProviderError>::into)
     }
 
-    fn multiproof(
-        &self,
-        input: TrieInput,
-        targets: HashMap<B256, HashSet<B256>>,
-    ) -> ProviderResult<MultiProof> {
+    fn multiproof(&self, input: TrieInput, targets: ProofTargets) -> ProviderResult<MultiProof> {
         Proof::overlay_multiproof(self.tx(), input, targets).map_err(Into::<ProviderError>::into)
     }
 
@@ -160,6 +271,12 @@ impl<Provider: DBProvider + BlockHashReader> StateProvider
     fn bytecode_by_hash(&self, code_hash: B256) -> ProviderResult<Option<Bytecode>> {
         self.tx().get::<tables::Bytecodes>(code_hash).map_err(Into::into)
     }
+
+    /// Check if the account exists without decompressing it.
+    fn account_exists(&self, address: Address) -> ProviderResult<bool> {
+        let mut cursor = self.tx().cursor_read::<RawTable<tables::PlainAccountState>>()?;
+        Ok(cursor.seek_exact(RawKey::new(address))?.is_some())
+    }
 }
 
 /// State provider for the latest state.
@@ -185,10 +302,199 @@ delegate_provider_impls!(LatestStateProvider<Provider> where [Provider: DBProvid
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_utils::create_test_provider_factory;
+    use alloy_primitives::{address, Bytes, U256};
+    use reth_db_api::transaction::DbTxMut;
+    use reth_primitives::StorageEntry;
 
     const fn assert_state_provider<T: StateProvider>() {}
     #[allow(dead_code)]
     const fn assert_latest_state_provider<T: DBProvider + BlockHashReader>() {
         assert_state_provider::<LatestStateProvider<T>>();
     }
+
+    #[test]
+    fn walk_contract_accounts() {
+        let factory = create_test_provider_factory();
+        let tx = factory.provider_rw().unwrap().into_tx();
+
+        let eoa = address!("0000000000000000000000000000000000000001");
+        let empty_code = address!("0000000000000000000000000000000000000002");
+        let contract1 = address!("0000000000000000000000000000000000000003");
+        let contract2 = address!("0000000000000000000000000000000000000004");
+        let code_hash1 = B256::with_last_byte(1);
+        let code_hash2 = B256::with_last_byte(2);
+
+        let account = |bytecode_hash| Account { nonce: 0, balance: U256::ZERO, bytecode_hash };
+        tx.put::<tables::PlainAccountState>(eoa, account(None)).unwrap();
+        tx.put::<tables::PlainAccountState>(empty_code, account(Some(KECCAK_EMPTY))).unwrap();
+        tx.put::<tables::PlainAccountState>(contract1, account(Some(code_hash1))).unwrap();
+        tx.put::<tables::PlainAccountState>(contract2, account(Some(code_hash2))).unwrap();
+        tx.commit().unwrap();
+
+        let provider = factory.provider().unwrap();
+        let state = LatestStateProviderRef::new(&provider);
+        let contracts =
+            state.walk_contract_accounts().unwrap().collect::<ProviderResult<Vec<_>>>().unwrap();
+        assert_eq!(contracts, vec![(contract1, code_hash1), (contract2, code_hash2)]);
+    }
+
+    #[test]
+    fn walk_contract_accounts_empty() {
+        let factory = create_test_provider_factory();
+        let provider = factory.provider().unwrap();
+        let state = LatestStateProviderRef::new(&provider);
+        assert_eq!(state.walk_contract_accounts().unwrap().count(), 0);
+    }
+
+    #[test]
+    fn account_exists() {
+        let factory = create_test_provider_factory();
+        let tx = factory.provider_rw().unwrap().into_tx();
+
+        let existing = address!("0000000000000000000000000000000000000001");
+        let missing = address!("0000000000000000000000000000000000000002");
+        tx.put::<tables::PlainAccountState>(existing, Account::default()).unwrap();
+        tx.commit().unwrap();
+
+        let provider = factory.provider().unwrap();
+        let state = LatestStateProviderRef::new(&provider);
+        assert!(state.account_exists(existing).unwrap());
+        assert!(!state.account_exists(missing).unwrap());
+    }
+
+    #[test]
+    fn storage_entries() {
+        let factory = create_test_provider_factory();
+        let tx = factory.provider_rw().unwrap().into_tx();
+
+        let address = address!("0000000000000000000000000000000000000001");
+        let other = address!("0000000000000000000000000000000000000002");
+        let entries = (1..=3)
+            .map(|i| StorageEntry { key: B256::with_last_byte(i), value: U256::from(i) })
+            .collect::<Vec<_>>();
+        for entry in entries.iter().rev() {
+            tx.put::<tables::PlainStorageState>(address, *entry).unwrap();
+        }
+        tx.put::<tables::PlainStorageState>(other, entries[0]).unwrap();
+        tx.commit().unwrap();
+
+        let provider = factory.provider().unwrap();
+        let state = LatestStateProviderRef::new(&provider);
+        assert_eq!(
+            state.storage_entries(address).unwrap().collect::<ProviderResult<Vec<_>>>().unwrap(),
+            entries.iter().map(|entry| (entry.key, entry.value)).collect::<Vec<_>>()
+        );
+
+        // Account without storage.
+        let empty = address!("0000000000000000000000000000000000000003");
+        assert_eq!(state.storage_entries(empty).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn bytecodes_for_accounts() {
+        let factory = create_test_provider_factory();
+        let tx = factory.provider_rw().unwrap().into_tx();
+
+        let eoa = address!("0000000000000000000000000000000000000001");
+        let contract1 = address!("0000000000000000000000000000000000000002");
+        let contract2 = address!("0000000000000000000000000000000000000003");
+        let missing = address!("0000000000000000000000000000000000000004");
+        let bytecode = Bytecode::new_raw(Bytes::from_static(&[0x60, 0x00]));
+        let code_hash = bytecode.hash_slow();
+
+        let account = |bytecode_hash| Account { nonce: 0, balance: U256::ZERO, bytecode_hash };
+        tx.put::<tables::PlainAccountState>(eoa, account(Some(KECCAK_EMPTY))).unwrap();
+        tx.put::<tables::PlainAccountState>(contract1, account(Some(code_hash))).unwrap();
+        tx.put::<tables::PlainAccountState>(contract2, account(Some(code_hash))).unwrap();
+        tx.put::<tables::Bytecodes>(code_hash, bytecode.clone()).unwrap();
+        tx.commit().unwrap();
+
+        let provider = factory.provider().unwrap();
+        let state = LatestStateProviderRef::new(&provider);
+        let bytecodes =
+            state.bytecodes_for_accounts(&[eoa, contract1, contract2, missing]).unwrap();
+        assert_eq!(bytecodes.len(), 1);
+        assert_eq!(bytecodes.get(&code_hash), Some(&bytecode));
+    }
+
+    #[test]
+    fn account_and_storage_slot_count() {
+        let factory = create_test_provider_factory();
+        let provider = factory.provider().unwrap();
+        let state = LatestStateProviderRef::new(&provider);
+        assert_eq!(state.account_count().unwrap(), 0);
+        assert_eq!(state.storage_slot_count().unwrap(), 0);
+        drop(provider);
+
+        let tx = factory.provider_rw().unwrap().into_tx();
+        let account = Account { nonce: 0, balance: U256::ZERO, bytecode_hash: None };
+        for i in 1..=3 {
+            let address = Address::with_last_byte(i);
+            tx.put::<tables::PlainAccountState>(address, account).unwrap();
+            for slot in 0..i {
+                let entry = StorageEntry { key: B256::with_last_byte(slot), value: U256::from(1) };
+                tx.put::<tables::PlainStorageState>(address, entry).unwrap();
+            }
+        }
+        tx.commit().unwrap();
+
+        let provider = factory.provider().unwrap();
+        let state = LatestStateProviderRef::new(&provider);
+        assert_eq!(state.account_count().unwrap(), 3);
+        assert_eq!(state.storage_slot_count().unwrap(), 6);
+    }
+
+    #[test]
+    fn all_code_hashes() {
+        let factory = create_test_provider_factory();
+        let tx = factory.provider_rw().unwrap().into_tx();
+        let mut code_hashes = (1..=3u8)
+            .map(|i| {
+                let bytecode = Bytecode::new_raw(Bytes::from(vec![0x60, i]));
+                let code_hash = bytecode.hash_slow();
+                tx.put::<tables::Bytecodes>(code_hash, bytecode).unwrap();
+                code_hash
+            })
+            .collect::<Vec<_>>();
+        tx.commit().unwrap();
+        code_hashes.sort_unstable();
+
+        let provider = factory.provider().unwrap();
+        let state = LatestStateProviderRef::new(&provider);
+        assert_eq!(
+            state.all_code_hashes().unwrap().collect::<ProviderResult<Vec<_>>>().unwrap(),
+            code_hashes
+        );
+    }
+
+    #[test]
+    fn walk_accounts_from() {
+        let factory = create_test_provider_factory();
+        let tx = factory.provider_rw().unwrap().into_tx();
+        let accounts = (1..=5)
+            .map(|i| {
+                let account = Account { nonce: i, balance: U256::ZERO, bytecode_hash: None };
+                (Address::with_last_byte(i as u8), account)
+            })
+            .collect::<Vec<_>>();
+        for (address, account) in &accounts {
+            tx.put::<tables::PlainAccountState>(*address, *account).unwrap();
+        }
+        tx.commit().unwrap();
+
+        let provider = factory.provider().unwrap();
+        let state = LatestStateProviderRef::new(&provider);
+
+        let (first, token) = state.walk_accounts_from(None, 3).unwrap();
+        assert_eq!(first, accounts[..3]);
+        assert_eq!(token, Some(accounts[3].0));
+
+        let (second, token) = state.walk_accounts_from(token, 3).unwrap();
+        assert_eq!(second, accounts[3..]);
+        assert_eq!(token, None);
+
+        // An exactly filled page has no token.
+        assert_eq!(state.walk_accounts_from(None, 5).unwrap(), (accounts, None));
+    }
 }
@@ -43,6 +43,22 @@ pub trait AccountExtReader: Send + Sync {
         &self,
         range: RangeInclusive<BlockNumber>,
     ) -> ProviderResult<BTreeMap<Address, Vec<BlockNumber>>>;
+
+    /// Iterate over account changesets and return all accounts that were destroyed alongside the
+    /// block they were destroyed in, ordered by block number and address.
+    ///
+    /// An account is considered destroyed in a block if it existed before the block and does not
+    /// exist after it. This covers `SELFDESTRUCT` as well as the removal of empty accounts
+    /// ([EIP-161](https://eips.ethereum.org/EIPS/eip-161)). Accounts that are destroyed and
+    /// re-created within the same block are not reported.
+    ///
+    /// Returns an error if the account history of the range was pruned or is not indexed yet.
+    ///
+    /// NOTE: Get inclusive range of blocks.
+    fn self_destructs_in_range(
+        &self,
+        range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<(BlockNumber, Address)>>;
 }
 
 /// AccountChange reader
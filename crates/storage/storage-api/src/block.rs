@@ -3,7 +3,7 @@ use crate::{
     TransactionsProvider, WithdrawalsProvider,
 };
 use alloy_consensus::Header;
-use alloy_eips::{BlockHashOrNumber, BlockId, BlockNumberOrTag};
+use alloy_eips::{eip4895::Withdrawals, BlockHashOrNumber, BlockId, BlockNumberOrTag};
 use alloy_primitives::{BlockNumber, B256};
 use reth_db_models::StoredBlockBodyIndices;
 use reth_primitives::{
@@ -103,6 +103,30 @@ pub trait BlockReader:
         self.block(num.into())
     }
 
+    /// Returns whether the block with matching hash is part of the canonical chain.
+    ///
+    /// Returns `false` if block is not found.
+    fn is_canonical_hash(&self, hash: B256) -> ProviderResult<bool> {
+        let Some(number) = self.block_number(hash)? else { return Ok(false) };
+        Ok(self.block_hash(number)? == Some(hash))
+    }
+
+    /// Returns the sealed block with matching number from database.
+    ///
+    /// Returns `None` if block is not found.
+    fn sealed_block_by_number(&self, num: u64) -> ProviderResult<Option<SealedBlock>> {
+        Ok(self.block(num.into())?.map(|block| block.seal_slow()))
+    }
+
+    /// Returns the withdrawals of the block with matching hash.
+    ///
+    /// Returns `None` both if the block is not found and if it predates Shanghai. The two cases
+    /// can be told apart with [`HeaderProvider::header`].
+    fn withdrawals_by_block_hash(&self, hash: B256) -> ProviderResult<Option<Withdrawals>> {
+        let Some(header) = self.header(&hash)? else { return Ok(None) };
+        self.withdrawals_by_block(hash.into(), header.timestamp)
+    }
+
     /// Returns the block body indices with matching number from database.
     ///
     /// Returns `None` if block is not found.
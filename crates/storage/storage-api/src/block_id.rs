@@ -19,6 +19,29 @@ pub trait BlockNumReader: BlockHashReader + Send + Sync {
     /// Returns the last block number associated with the last canonical header in the database.
     fn last_block_number(&self) -> ProviderResult<BlockNumber>;
 
+    /// Returns the number and hash of the last `count` canonical blocks up to and including the
+    /// best block, in ascending order.
+    ///
+    /// Near genesis fewer than `count` hashes are returned, starting at the genesis block. Blocks
+    /// without a canonical hash are skipped.
+    ///
+    /// Implementations that read the tip and the hashes from separate snapshots should override
+    /// this, so that a concurrent commit or unwind can't mix the two.
+    fn recent_block_hashes(&self, count: u64) -> ProviderResult<Vec<(BlockNumber, B256)>> {
+        if count == 0 {
+            return Ok(Vec::new())
+        }
+        let best = self.best_block_number()?;
+        let start = best.saturating_sub(count - 1);
+        let mut hashes = Vec::new();
+        for number in start..=best {
+            if let Some(hash) = self.block_hash(number)? {
+                hashes.push((number, hash));
+            }
+        }
+        Ok(hashes)
+    }
+
     /// Gets the `BlockNumber` for the given hash. Returns `None` if no block with this hash exists.
     fn block_number(&self, hash: B256) -> ProviderResult<Option<BlockNumber>>;
 
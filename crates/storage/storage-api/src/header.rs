@@ -1,6 +1,6 @@
 use alloy_consensus::Header;
 use alloy_eips::BlockHashOrNumber;
-use alloy_primitives::{BlockHash, BlockNumber, U256};
+use alloy_primitives::{map::HashMap, BlockHash, BlockNumber, U256};
 use reth_primitives::SealedHeader;
 use reth_storage_errors::provider::ProviderResult;
 use std::ops::RangeBounds;
@@ -21,6 +21,22 @@ pub trait HeaderProvider: Send + Sync {
         Ok(self.header(&block_hash)?.map(|header| SealedHeader::new(header, block_hash)))
     }
 
+    /// Get headers by block hashes.
+    ///
+    /// Headers of unknown blocks are absent from the returned map.
+    fn headers_by_hash(
+        &self,
+        block_hashes: &[BlockHash],
+    ) -> ProviderResult<HashMap<BlockHash, Header>> {
+        let mut headers = HashMap::with_capacity_and_hasher(block_hashes.len(), Default::default());
+        for block_hash in block_hashes {
+            if let Some(header) = self.header(block_hash)? {
+                headers.insert(*block_hash, header);
+            }
+        }
+        Ok(headers)
+    }
+
     /// Get header by block number
     fn header_by_number(&self, num: u64) -> ProviderResult<Option<Header>>;
 
@@ -2,12 +2,15 @@ use super::{
     AccountReader, BlockHashReader, BlockIdReader, StateProofProvider, StateRootProvider,
     StorageRootProvider,
 };
-use alloy_consensus::constants::KECCAK_EMPTY;
+use alloy_consensus::constants::{EMPTY_ROOT_HASH, KECCAK_EMPTY};
 use alloy_eips::{BlockId, BlockNumberOrTag};
-use alloy_primitives::{Address, BlockHash, BlockNumber, StorageKey, StorageValue, B256, U256};
+use alloy_primitives::{
+    map::HashMap, Address, BlockHash, BlockNumber, StorageKey, StorageValue, B256, U256,
+};
 use auto_impl::auto_impl;
 use reth_primitives::Bytecode;
 use reth_storage_errors::provider::ProviderResult;
+use reth_trie::{AccountProof, TrieInput};
 
 /// Type alias of boxed [`StateProvider`].
 pub type StateProviderBox = Box<dyn StateProvider>;
@@ -33,6 +36,14 @@ pub trait StateProvider:
     /// Get account code by its hash
     fn bytecode_by_hash(&self, code_hash: B256) -> ProviderResult<Option<Bytecode>>;
 
+    /// Returns `true` if the account at the given address exists.
+    ///
+    /// Implementations backed by a database should override this to check for the key without
+    /// decoding the account.
+    fn account_exists(&self, address: Address) -> ProviderResult<bool> {
+        Ok(self.basic_account(address)?.is_some())
+    }
+
     /// Get account code by its address.
     ///
     /// Returns `None` if the account doesn't exist or account is not a contract
@@ -56,6 +67,45 @@ pub trait StateProvider:
         Ok(None)
     }
 
+    /// Get the account proof of the account at the given address, without any storage proofs.
+    ///
+    /// For accounts that don't exist, the returned proof is an exclusion proof.
+    fn account_proof(&self, address: Address) -> ProviderResult<AccountProof> {
+        self.proof(TrieInput::default(), address, &[])
+    }
+
+    /// Get the storage root of the account at the given address.
+    ///
+    /// Returns `None` if the account has no storage.
+    fn account_storage_root(&self, address: Address) -> ProviderResult<Option<B256>> {
+        let storage_root = self.storage_root(address, Default::default())?;
+        Ok((storage_root != EMPTY_ROOT_HASH).then_some(storage_root))
+    }
+
+    /// Get the bytecode of all given accounts, keyed by code hash.
+    ///
+    /// Accounts that don't exist or are not contracts are skipped. Accounts sharing the same code
+    /// hash result in a single entry.
+    fn bytecodes_for_accounts(
+        &self,
+        addresses: &[Address],
+    ) -> ProviderResult<HashMap<B256, Bytecode>> {
+        let mut bytecodes = HashMap::default();
+        for address in addresses {
+            let Some(code_hash) = self.basic_account(*address)?.and_then(|acc| acc.bytecode_hash)
+            else {
+                continue
+            };
+            if code_hash == KECCAK_EMPTY || bytecodes.contains_key(&code_hash) {
+                continue
+            }
+            if let Some(bytecode) = self.bytecode_by_hash(code_hash)? {
+                bytecodes.insert(code_hash, bytecode);
+            }
+        }
+        Ok(bytecodes)
+    }
+
     /// Get account balance by its address.
     ///
     /// Returns `None` if the account doesn't exist
@@ -1,4 +1,4 @@
-use alloy_primitives::{Address, BlockNumber, B256};
+use alloy_primitives::{Address, BlockNumber, B256, U256};
 use reth_db_api::models::BlockNumberAddress;
 use reth_primitives::StorageEntry;
 use reth_storage_errors::provider::ProviderResult;
@@ -30,6 +30,26 @@ pub trait StorageReader: Send + Sync {
         &self,
         range: RangeInclusive<BlockNumber>,
     ) -> ProviderResult<BTreeMap<(Address, B256), Vec<u64>>>;
+
+    /// Returns the storage slots of the account changed in the given block, alongside their
+    /// values before and after the block.
+    ///
+    /// Returns an empty list if the account storage was not changed in the block, and an error if
+    /// the storage history of the block was pruned or is not indexed yet.
+    fn storage_changes_for_account(
+        &self,
+        address: Address,
+        block_number: BlockNumber,
+    ) -> ProviderResult<Vec<(B256, U256, U256)>>;
+
+    /// Returns the blocks within the range that changed the value of the storage slot, alongside
+    /// the slot value after each of them.
+    fn storage_history(
+        &self,
+        address: Address,
+        slot: B256,
+        range: RangeInclusive<BlockNumber>,
+    ) -> ProviderResult<Vec<(BlockNumber, U256)>>;
 }
 
 /// Storage ChangeSet reader
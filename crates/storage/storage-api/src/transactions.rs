@@ -51,6 +51,19 @@ pub trait TransactionsProvider: BlockNumReader + Send + Sync {
     /// Get transaction block number
     fn transaction_block(&self, id: TxNumber) -> ProviderResult<Option<BlockNumber>>;
 
+    /// Get the number of the block the transaction was mined in and the index of the transaction
+    /// within that block, by transaction hash.
+    ///
+    /// Returns None if the transaction is not found.
+    fn transaction_block_and_index(
+        &self,
+        hash: TxHash,
+    ) -> ProviderResult<Option<(BlockNumber, u64)>> {
+        Ok(self
+            .transaction_by_hash_with_meta(hash)?
+            .map(|(_, meta)| (meta.block_number, meta.index)))
+    }
+
     /// Get transactions by block id.
     fn transactions_by_block(
         &self,
@@ -1,11 +1,9 @@
-use alloy_primitives::{
-    map::{HashMap, HashSet},
-    Address, Bytes, B256,
-};
+use alloy_primitives::{map::HashMap, Address, Bytes, B256};
 use reth_storage_errors::provider::ProviderResult;
 use reth_trie::{
     updates::{StorageTrieUpdates, TrieUpdates},
-    AccountProof, HashedPostState, HashedStorage, MultiProof, StorageProof, TrieInput,
+    AccountProof, BranchNodeCompact, HashedPostState, HashedStorage, MultiProof, Nibbles,
+    ProofTargets, StorageMultiProof, StorageProof, TrieInput,
 };
 
 /// A type that can compute the state root of a given post state.
@@ -56,6 +54,18 @@ pub trait StorageRootProvider: Send + Sync {
         slot: B256,
         hashed_storage: HashedStorage,
     ) -> ProviderResult<StorageProof>;
+
+    /// Returns the storage multiproof of the `HashedStorage` for target slots on top of the
+    /// current state.
+    ///
+    /// The result can be inserted into [`MultiProof::storages`] under the hashed address to
+    /// assemble the proofs of several accounts.
+    fn storage_multiproof(
+        &self,
+        address: Address,
+        slots: &[B256],
+        hashed_storage: HashedStorage,
+    ) -> ProviderResult<StorageMultiProof>;
 }
 
 /// A type that can generate state proof on top of a given post state.
@@ -72,11 +82,7 @@ pub trait StateProofProvider: Send + Sync {
 
     /// Generate [`MultiProof`] for target hashed account and corresponding
     /// hashed storage slot keys.
-    fn multiproof(
-        &self,
-        input: TrieInput,
-        targets: HashMap<B256, HashSet<B256>>,
-    ) -> ProviderResult<MultiProof>;
+    fn multiproof(&self, input: TrieInput, targets: ProofTargets) -> ProviderResult<MultiProof>;
 
     /// Get trie witness for provided state.
     fn witness(
@@ -95,6 +101,20 @@ pub trait TrieWriter: Send + Sync {
     fn write_trie_updates(&self, trie_updates: &TrieUpdates) -> ProviderResult<usize>;
 }
 
+/// Trie Reader
+pub trait TrieReader: Send + Sync {
+    /// Returns an iterator over the nodes of the account trie in path order.
+    fn walk_account_trie(
+        &self,
+    ) -> ProviderResult<impl Iterator<Item = ProviderResult<(Nibbles, BranchNodeCompact)>>>;
+
+    /// Returns an iterator over the nodes of the storage trie of the account in path order.
+    fn walk_storage_trie(
+        &self,
+        hashed_address: B256,
+    ) -> ProviderResult<impl Iterator<Item = ProviderResult<(Nibbles, BranchNodeCompact)>>>;
+}
+
 /// Storage Trie Writer
 #[auto_impl::auto_impl(&, Arc, Box)]
 pub trait StorageTrieWriter: Send + Sync {
@@ -2,7 +2,7 @@
 
 use crate::{Nibbles, TrieAccount};
 use alloy_consensus::constants::KECCAK_EMPTY;
-use alloy_primitives::{keccak256, Address, Bytes, B256, U256};
+use alloy_primitives::{keccak256, Address, Bytes, Keccak256, B256, U256};
 use alloy_rlp::{encode_fixed_size, Decodable, EMPTY_STRING_CODE};
 use alloy_trie::{
     nodes::TrieNode,
@@ -79,20 +79,100 @@ impl MultiProof {
 
     /// Extends this multiproof with another one, merging both account and storage
     /// proofs.
+    ///
+    /// Proofs computed against the same state must agree on every node they have in common, so
+    /// merging them in any order yields the same multiproof. With debug assertions enabled, a node
+    /// path present in both proofs with different bytes causes a panic.
     pub fn extend(&mut self, other: Self) {
-        self.account_subtree.extend_from(other.account_subtree);
+        self.extend_with_stats(other);
+    }
+
+    /// Extends this multiproof with another one, merging both account and storage
+    /// proofs, and returns how many of the merged nodes were new and how many were already
+    /// present.
+    ///
+    /// A high duplicate ratio across merged proofs means that they overlap heavily, usually at
+    /// the top of the trie.
+    ///
+    /// See [`Self::extend`] for the invariant on nodes present in both proofs.
+    pub fn extend_with_stats(&mut self, other: Self) -> MultiProofExtendStats {
+        let mut stats = MultiProofExtendStats::default();
+        stats.extend_nodes(&mut self.account_subtree, other.account_subtree);
 
         for (hashed_address, storage) in other.storages {
             match self.storages.entry(hashed_address) {
                 hash_map::Entry::Occupied(mut entry) => {
                     debug_assert_eq!(entry.get().root, storage.root);
-                    entry.get_mut().subtree.extend_from(storage.subtree);
+                    stats.extend_nodes(&mut entry.get_mut().subtree, storage.subtree);
                 }
                 hash_map::Entry::Vacant(entry) => {
+                    stats.inserted += storage.subtree.len() as u64;
                     entry.insert(storage);
                 }
             }
         }
+
+        stats
+    }
+
+    /// Returns a deterministic hash of the proof content.
+    ///
+    /// The hash covers all account nodes and every storage multiproof, including its root, and
+    /// does not depend on the order in which nodes were inserted. Two multiproofs that contain
+    /// the same nodes therefore hash identically, no matter how they were assembled with
+    /// [`Self::extend`].
+    pub fn content_hash(&self) -> B256 {
+        let mut hasher = Keccak256::new();
+        hash_proof_nodes(&mut hasher, &self.account_subtree);
+        hasher.update((self.storages.len() as u64).to_be_bytes());
+        for (hashed_address, storage) in self.storages.iter().sorted_unstable_by_key(|(k, _)| *k) {
+            hasher.update(hashed_address);
+            hasher.update(storage.root);
+            hash_proof_nodes(&mut hasher, &storage.subtree);
+        }
+        hasher.finalize()
+    }
+}
+
+/// Feeds the proof nodes into the hasher, sorted by path.
+///
+/// Paths and nodes are length-prefixed so that the input is unambiguous.
+fn hash_proof_nodes(hasher: &mut Keccak256, nodes: &ProofNodes) {
+    hasher.update((nodes.len() as u64).to_be_bytes());
+    for (path, node) in nodes.iter().sorted_unstable_by_key(|(path, _)| *path) {
+        hasher.update((path.len() as u64).to_be_bytes());
+        hasher.update(path.as_slice());
+        hasher.update((node.len() as u64).to_be_bytes());
+        hasher.update(node);
+    }
+}
+
+/// Node deduplication statistics of [`MultiProof::extend_with_stats`].
+#[derive(Clone, Copy, Default, Debug, PartialEq, Eq)]
+pub struct MultiProofExtendStats {
+    /// The number of nodes that were not present in the extended multiproof.
+    pub inserted: u64,
+    /// The number of nodes that were already present in the extended multiproof.
+    pub duplicates: u64,
+}
+
+impl MultiProofExtendStats {
+    /// Moves all nodes from `other` into `nodes`, counting new and duplicate nodes.
+    ///
+    /// Panics in debug builds if a node already present in `nodes` differs from the one in
+    /// `other`.
+    fn extend_nodes(&mut self, nodes: &mut ProofNodes, other: ProofNodes) {
+        for (path, node) in other.into_inner() {
+            debug_assert!(
+                nodes.get(&path).map_or(true, |existing| existing == &node),
+                "conflicting proof nodes at path {path:?}"
+            );
+            if nodes.insert(path, node).is_some() {
+                self.duplicates += 1;
+            } else {
+                self.inserted += 1;
+            }
+        }
     }
 }
 
@@ -330,4 +410,86 @@ mod tests {
         assert!(storage.subtree.contains_key(&Nibbles::from_nibbles(vec![0])));
         assert!(storage.subtree.contains_key(&Nibbles::from_nibbles(vec![1])));
     }
+
+    #[test]
+    fn test_multiproof_extend_stats() {
+        let mut proof1 = MultiProof::default();
+        let mut proof2 = MultiProof::default();
+
+        let root_node: Bytes = alloy_rlp::encode_fixed_size(&U256::from(1)).to_vec().into();
+        let node: Bytes = alloy_rlp::encode_fixed_size(&U256::from(2)).to_vec().into();
+
+        // Both proofs share the root node.
+        proof1.account_subtree.insert(Nibbles::default(), root_node.clone());
+        proof2.account_subtree.insert(Nibbles::default(), root_node);
+        proof2.account_subtree.insert(Nibbles::from_nibbles(vec![1]), node.clone());
+
+        // Storage proof of an account unknown to `proof1`.
+        let addr = B256::random();
+        let mut subtree = ProofNodes::default();
+        subtree.insert(Nibbles::default(), node);
+        proof2.storages.insert(addr, StorageMultiProof { root: B256::random(), subtree });
+
+        let stats = proof1.extend_with_stats(proof2.clone());
+        assert_eq!(stats, MultiProofExtendStats { inserted: 2, duplicates: 1 });
+
+        // Extending with the same proof again only yields duplicates.
+        let stats = proof1.extend_with_stats(proof2);
+        assert_eq!(stats, MultiProofExtendStats { inserted: 0, duplicates: 3 });
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "conflicting proof nodes")]
+    fn test_multiproof_extend_conflicting_nodes() {
+        let mut proof1 = MultiProof::default();
+        let mut proof2 = MultiProof::default();
+
+        proof1.account_subtree.insert(
+            Nibbles::default(),
+            alloy_rlp::encode_fixed_size(&U256::from(1)).to_vec().into(),
+        );
+        proof2.account_subtree.insert(
+            Nibbles::default(),
+            alloy_rlp::encode_fixed_size(&U256::from(2)).to_vec().into(),
+        );
+
+        proof1.extend(proof2);
+    }
+
+    #[test]
+    fn test_multiproof_content_hash() {
+        let node =
+            |n: u8| -> Bytes { alloy_rlp::encode_fixed_size(&U256::from(n)).to_vec().into() };
+        let addr = B256::random();
+        let root = B256::random();
+
+        let single_path_proof = |path: u8| {
+            let mut proof = MultiProof::default();
+            proof.account_subtree.insert(Nibbles::from_nibbles(vec![path]), node(path));
+            let mut subtree = ProofNodes::default();
+            subtree.insert(Nibbles::from_nibbles(vec![path]), node(path + 10));
+            proof.storages.insert(addr, StorageMultiProof { root, subtree });
+            proof
+        };
+
+        // Same node set, assembled in different orders.
+        let mut proof1 = single_path_proof(1);
+        proof1.extend(single_path_proof(2));
+        proof1.extend(single_path_proof(3));
+        let mut proof2 = single_path_proof(3);
+        proof2.extend(single_path_proof(1));
+        proof2.extend(single_path_proof(2));
+        assert_eq!(proof1, proof2);
+        assert_eq!(proof1.content_hash(), proof2.content_hash());
+
+        // Different content yields a different hash.
+        assert_ne!(proof1.content_hash(), MultiProof::default().content_hash());
+        let mut proof3 = proof1.clone();
+        proof3.account_subtree.insert(Nibbles::from_nibbles(vec![1]), node(4));
+        assert_ne!(proof1.content_hash(), proof3.content_hash());
+        let mut proof4 = proof1.clone();
+        proof4.storages.get_mut(&addr).unwrap().root = B256::random();
+        assert_ne!(proof1.content_hash(), proof4.content_hash());
+    }
 }
@@ -0,0 +1,164 @@
+use alloy_primitives::{
+    map::{hash_map, HashMap, HashSet},
+    B256,
+};
+
+/// Targets of a state multiproof.
+///
+/// Maps hashed addresses of the accounts to prove to the hashed storage slots to prove for each
+/// of them. An account without storage slots is still a target.
+#[derive(Clone, Default, Debug, PartialEq, Eq)]
+pub struct ProofTargets(HashMap<B256, HashSet<B256>>);
+
+impl ProofTargets {
+    /// Adds an account target.
+    pub fn add_account(&mut self, hashed_address: B256) {
+        self.0.entry(hashed_address).or_default();
+    }
+
+    /// Adds a storage slot target, along with its account.
+    pub fn add_slot(&mut self, hashed_address: B256, hashed_slot: B256) {
+        self.0.entry(hashed_address).or_default().insert(hashed_slot);
+    }
+
+    /// Adds storage slot targets of a single account, along with the account.
+    pub fn add_slots(
+        &mut self,
+        hashed_address: B256,
+        hashed_slots: impl IntoIterator<Item = B256>,
+    ) {
+        self.0.entry(hashed_address).or_default().extend(hashed_slots);
+    }
+
+    /// Merges the targets of `other` into this one.
+    pub fn merge(&mut self, other: Self) {
+        for (hashed_address, hashed_slots) in other.0 {
+            self.add_slots(hashed_address, hashed_slots);
+        }
+    }
+
+    /// Returns the total number of account and storage slot targets.
+    pub fn len(&self) -> usize {
+        self.account_count() + self.slot_count()
+    }
+
+    /// Returns `true` if there are no targets.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the number of account targets.
+    pub fn account_count(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns the number of storage slot targets across all accounts.
+    pub fn slot_count(&self) -> usize {
+        self.0.values().map(HashSet::len).sum()
+    }
+
+    /// Returns the storage slot targets of the account, or `None` if the account is not a target.
+    pub fn get(&self, hashed_address: &B256) -> Option<&HashSet<B256>> {
+        self.0.get(hashed_address)
+    }
+
+    /// Removes the account target and returns its storage slot targets.
+    pub fn remove(&mut self, hashed_address: &B256) -> Option<HashSet<B256>> {
+        self.0.remove(hashed_address)
+    }
+
+    /// Returns an iterator over the account targets.
+    pub fn accounts(&self) -> hash_map::Keys<'_, B256, HashSet<B256>> {
+        self.0.keys()
+    }
+
+    /// Returns an iterator over the account targets and their storage slot targets.
+    pub fn iter(&self) -> hash_map::Iter<'_, B256, HashSet<B256>> {
+        self.0.iter()
+    }
+}
+
+impl From<HashMap<B256, HashSet<B256>>> for ProofTargets {
+    fn from(targets: HashMap<B256, HashSet<B256>>) -> Self {
+        Self(targets)
+    }
+}
+
+impl FromIterator<(B256, HashSet<B256>)> for ProofTargets {
+    fn from_iter<T: IntoIterator<Item = (B256, HashSet<B256>)>>(iter: T) -> Self {
+        let mut targets = Self::default();
+        for (hashed_address, hashed_slots) in iter {
+            targets.add_slots(hashed_address, hashed_slots);
+        }
+        targets
+    }
+}
+
+impl IntoIterator for ProofTargets {
+    type Item = (B256, HashSet<B256>);
+    type IntoIter = hash_map::IntoIter<B256, HashSet<B256>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a ProofTargets {
+    type Item = (&'a B256, &'a HashSet<B256>);
+    type IntoIter = hash_map::Iter<'a, B256, HashSet<B256>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts() {
+        let mut targets = ProofTargets::default();
+        assert!(targets.is_empty());
+        assert_eq!(targets.len(), 0);
+
+        targets.add_account(B256::with_last_byte(1));
+        targets.add_slot(B256::with_last_byte(2), B256::with_last_byte(1));
+        targets.add_slot(B256::with_last_byte(2), B256::with_last_byte(2));
+        // Adding a slot twice or an account with slots as an account target is a no-op.
+        targets.add_slot(B256::with_last_byte(2), B256::with_last_byte(2));
+        targets.add_account(B256::with_last_byte(2));
+
+        assert!(!targets.is_empty());
+        assert_eq!(targets.account_count(), 2);
+        assert_eq!(targets.slot_count(), 2);
+        assert_eq!(targets.len(), 4);
+        assert_eq!(targets.get(&B256::with_last_byte(1)), Some(&HashSet::default()));
+    }
+
+    #[test]
+    fn merge() {
+        let shared = B256::with_last_byte(1);
+        let mut targets = ProofTargets::from_iter([
+            (shared, HashSet::from_iter([B256::with_last_byte(1)])),
+            (B256::with_last_byte(2), HashSet::default()),
+        ]);
+        let other = ProofTargets::from_iter([
+            (shared, HashSet::from_iter([B256::with_last_byte(1), B256::with_last_byte(2)])),
+            (B256::with_last_byte(3), HashSet::from_iter([B256::with_last_byte(3)])),
+        ]);
+
+        targets.merge(other);
+        assert_eq!(targets.account_count(), 3);
+        assert_eq!(targets.slot_count(), 3);
+        assert_eq!(
+            targets.get(&shared),
+            Some(&HashSet::from_iter([B256::with_last_byte(1), B256::with_last_byte(2)]))
+        );
+        assert_eq!(targets.get(&B256::with_last_byte(2)), Some(&HashSet::default()));
+        assert_eq!(
+            targets.get(&B256::with_last_byte(3)),
+            Some(&HashSet::from_iter([B256::with_last_byte(3)]))
+        );
+    }
+}
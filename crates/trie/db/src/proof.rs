@@ -1,18 +1,14 @@
 use crate::{DatabaseHashedCursorFactory, DatabaseTrieCursorFactory};
-use alloy_primitives::{
-    keccak256,
-    map::{HashMap, HashSet},
-    Address, B256,
-};
+use alloy_primitives::{keccak256, map::HashMap, Address, B256};
 use reth_db_api::transaction::DbTx;
 use reth_execution_errors::StateProofError;
 use reth_trie::{
     hashed_cursor::HashedPostStateCursorFactory,
     proof::{Proof, StorageProof},
     trie_cursor::InMemoryTrieCursorFactory,
-    HashedPostStateSorted, HashedStorage, MultiProof, TrieInput,
+    HashedPostStateSorted, HashedStorage, MultiProof, ProofTargets, TrieInput,
 };
-use reth_trie_common::AccountProof;
+use reth_trie_common::{AccountProof, StorageMultiProof};
 
 /// Extends [`Proof`] with operations specific for working with a database transaction.
 pub trait DatabaseProof<'a, TX> {
@@ -31,7 +27,7 @@ pub trait DatabaseProof<'a, TX> {
     fn overlay_multiproof(
         tx: &'a TX,
         input: TrieInput,
-        targets: HashMap<B256, HashSet<B256>>,
+        targets: ProofTargets,
     ) -> Result<MultiProof, StateProofError>;
 }
 
@@ -67,7 +63,7 @@ impl<'a, TX: DbTx> DatabaseProof<'a, TX>
     fn overlay_multiproof(
         tx: &'a TX,
         input: TrieInput,
-        targets: HashMap<B256, HashSet<B256>>,
+        targets: ProofTargets,
     ) -> Result<MultiProof, StateProofError> {
         let nodes_sorted = input.nodes.into_sorted();
         let state_sorted = input.state.into_sorted();
@@ -97,6 +93,14 @@ pub trait DatabaseStorageProof<'a, TX> {
         slot: B256,
         storage: HashedStorage,
     ) -> Result<reth_trie_common::StorageProof, StateProofError>;
+
+    /// Generates the storage multiproof for target slots based on [`TrieInput`].
+    fn overlay_storage_multiproof(
+        tx: &'a TX,
+        address: Address,
+        slots: &[B256],
+        storage: HashedStorage,
+    ) -> Result<StorageMultiProof, StateProofError>;
 }
 
 impl<'a, TX: DbTx> DatabaseStorageProof<'a, TX>
@@ -126,4 +130,26 @@ impl<'a, TX: DbTx> DatabaseStorageProof<'a, TX>
             .with_prefix_set_mut(prefix_set)
             .storage_proof(slot)
     }
+
+    fn overlay_storage_multiproof(
+        tx: &'a TX,
+        address: Address,
+        slots: &[B256],
+        storage: HashedStorage,
+    ) -> Result<StorageMultiProof, StateProofError> {
+        let hashed_address = keccak256(address);
+        let targets = slots.iter().map(keccak256).collect();
+        let prefix_set = storage.construct_prefix_set();
+        let state_sorted = HashedPostStateSorted::new(
+            Default::default(),
+            HashMap::from([(hashed_address, storage.into_sorted())]),
+        );
+        Self::from_tx(tx, address)
+            .with_hashed_cursor_factory(HashedPostStateCursorFactory::new(
+                DatabaseHashedCursorFactory::new(tx),
+                &state_sorted,
+            ))
+            .with_prefix_set_mut(prefix_set)
+            .storage_multiproof(targets)
+    }
 }
@@ -1,12 +1,19 @@
 #![allow(missing_docs)]
 
 use alloy_consensus::EMPTY_ROOT_HASH;
-use alloy_primitives::{keccak256, Address, Bytes, B256, U256};
+use alloy_primitives::{
+    keccak256,
+    map::{HashMap, HashSet},
+    Address, Bytes, B256, U256,
+};
 use alloy_rlp::EMPTY_STRING_CODE;
 use reth_chainspec::{Chain, ChainSpec, HOLESKY, MAINNET};
 use reth_primitives::Account;
-use reth_provider::test_utils::{create_test_provider_factory, insert_genesis};
-use reth_trie::{proof::Proof, Nibbles};
+use reth_provider::{
+    test_utils::{create_test_provider_factory, insert_genesis},
+    StateProvider, StorageRootProvider,
+};
+use reth_trie::{proof::Proof, HashedStorage, MultiProof, Nibbles, ProofTargets};
 use reth_trie_common::{AccountProof, StorageProof};
 use reth_trie_db::DatabaseProof;
 use std::{
@@ -170,6 +177,27 @@ fn mainnet_genesis_account_proof_nonexistent() {
     assert_eq!(account_proof.verify(root), Ok(()));
 }
 
+#[test]
+fn mainnet_genesis_account_proof_nonexistent_from_state_provider() {
+    // Create test database and insert genesis accounts.
+    let factory = create_test_provider_factory();
+    let root = insert_genesis(&factory, MAINNET.clone()).unwrap();
+
+    // Address that does not exist in mainnet genesis allocation.
+    let target = Address::from_str("0x000d836201318ec6899a67540690382780743281").unwrap();
+
+    let account_proof = factory.latest().unwrap().account_proof(target).unwrap();
+    assert_eq!(account_proof.info, None);
+    assert!(account_proof.storage_proofs.is_empty());
+    assert_eq!(account_proof.verify(root), Ok(()));
+
+    let provider = factory.provider().unwrap();
+    assert_eq!(
+        account_proof,
+        Proof::from_tx(provider.tx_ref()).account_proof(target, &[]).unwrap()
+    );
+}
+
 #[test]
 fn holesky_deposit_contract_proof() {
     // Create test database and insert genesis accounts.
@@ -255,3 +283,68 @@ fn holesky_deposit_contract_proof() {
     similar_asserts::assert_eq!(account_proof, expected);
     assert_eq!(account_proof.verify(root), Ok(()));
 }
+
+#[test]
+fn holesky_deposit_contract_storage_multiproof() {
+    // Create test database and insert genesis accounts.
+    let factory = create_test_provider_factory();
+    let root = insert_genesis(&factory, HOLESKY.clone()).unwrap();
+
+    let target = Address::from_str("0x4242424242424242424242424242424242424242").unwrap();
+    let hashed_address = keccak256(target);
+    // existent and non-existent
+    let slots = Vec::from([B256::with_last_byte(0x22), B256::with_last_byte(0xff)]);
+
+    let storage_multiproof = factory
+        .latest()
+        .unwrap()
+        .storage_multiproof(target, &slots, HashedStorage::default())
+        .unwrap();
+
+    let provider = factory.provider().unwrap();
+
+    // The storage multiproof matches the storage part of a full multiproof.
+    let targets = ProofTargets::from_iter([(
+        hashed_address,
+        slots.iter().map(keccak256).collect::<HashSet<_>>(),
+    )]);
+    let multiproof = Proof::from_tx(provider.tx_ref()).multiproof(targets).unwrap();
+    assert_eq!(multiproof.storages.get(&hashed_address), Some(&storage_multiproof));
+
+    // It composes with an account-only multiproof into a full one.
+    let mut composed = Proof::from_tx(provider.tx_ref())
+        .multiproof(ProofTargets::from_iter([(hashed_address, HashSet::default())]))
+        .unwrap();
+    composed.extend(MultiProof {
+        account_subtree: Default::default(),
+        storages: HashMap::from_iter([(hashed_address, storage_multiproof)]),
+    });
+    let account_proof = composed.account_proof(target, &slots).unwrap();
+    assert_eq!(
+        account_proof,
+        Proof::from_tx(provider.tx_ref()).account_proof(target, &slots).unwrap()
+    );
+    assert_eq!(account_proof.verify(root), Ok(()));
+}
+
+#[test]
+fn holesky_deposit_contract_storage_root() {
+    // Create test database and insert genesis accounts.
+    let factory = create_test_provider_factory();
+    insert_genesis(&factory, HOLESKY.clone()).unwrap();
+
+    let state = factory.latest().unwrap();
+
+    let target = Address::from_str("0x4242424242424242424242424242424242424242").unwrap();
+    assert_eq!(
+        state.account_storage_root(target).unwrap(),
+        Some(
+            B256::from_str("0x556a482068355939c95a3412bdb21213a301483edb1b64402fb66ac9f3583599")
+                .unwrap()
+        )
+    );
+
+    // Account without storage.
+    let eoa = Address::from_str("0x0000000000000000000000000000000000000000").unwrap();
+    assert_eq!(state.account_storage_root(eoa).unwrap(), None);
+}
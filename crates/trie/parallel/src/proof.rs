@@ -17,7 +17,8 @@ use reth_trie::{
     proof::StorageProof,
     trie_cursor::{InMemoryTrieCursorFactory, TrieCursorFactory},
     walker::TrieWalker,
-    HashBuilder, MultiProof, Nibbles, TrieAccount, TrieInput, TRIE_ACCOUNT_RLP_MAX_SIZE,
+    HashBuilder, MultiProof, Nibbles, ProofTargets, TrieAccount, TrieInput,
+    TRIE_ACCOUNT_RLP_MAX_SIZE,
 };
 use reth_trie_common::proof::ProofRetainer;
 use reth_trie_db::{DatabaseHashedCursorFactory, DatabaseTrieCursorFactory};
@@ -56,10 +57,7 @@ where
     Factory: DatabaseProviderFactory<Provider: BlockReader> + Clone + Send + Sync + 'static,
 {
     /// Generate a state multiproof according to specified targets.
-    pub fn multiproof(
-        self,
-        targets: HashMap<B256, HashSet<B256>>,
-    ) -> Result<MultiProof, ParallelStateRootError> {
+    pub fn multiproof(self, targets: ProofTargets) -> Result<MultiProof, ParallelStateRootError> {
         let mut tracker = ParallelTrieTracker::default();
 
         let trie_nodes_sorted = Arc::new(self.input.nodes.into_sorted());
@@ -68,7 +66,9 @@ where
         // Extend prefix sets with targets
         let mut prefix_sets = self.input.prefix_sets.clone();
         prefix_sets.extend(TriePrefixSetsMut {
-            account_prefix_set: PrefixSetMut::from(targets.keys().copied().map(Nibbles::unpack)),
+            account_prefix_set: PrefixSetMut::from(
+                targets.accounts().copied().map(Nibbles::unpack),
+            ),
             storage_prefix_sets: targets
                 .iter()
                 .filter(|&(_hashed_address, slots)| (!slots.is_empty()))
@@ -151,7 +151,7 @@ where
         .with_deletions_retained(true);
 
         // Create a hash builder to rebuild the root node since it is not available in the database.
-        let retainer: ProofRetainer = targets.keys().map(Nibbles::unpack).collect();
+        let retainer: ProofRetainer = targets.accounts().map(Nibbles::unpack).collect();
         let mut hash_builder = HashBuilder::default().with_proof_retainer(retainer);
 
         let mut storages = HashMap::default();
@@ -270,8 +270,7 @@ mod tests {
             provider_rw.commit().unwrap();
         }
 
-        let mut targets =
-            HashMap::<B256, HashSet<B256, DefaultHashBuilder>, DefaultHashBuilder>::default();
+        let mut targets = ProofTargets::default();
         for (address, (_, storage)) in state.iter().take(10) {
             let hashed_address = keccak256(*address);
             let mut target_slots = HashSet::<B256, DefaultHashBuilder>::default();
@@ -281,7 +280,7 @@ mod tests {
             }
 
             if !target_slots.is_empty() {
-                targets.insert(hashed_address, target_slots);
+                targets.add_slots(hashed_address, target_slots);
             }
         }
 
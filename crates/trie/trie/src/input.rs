@@ -1,4 +1,9 @@
-use crate::{prefix_set::TriePrefixSetsMut, updates::TrieUpdates, HashedPostState};
+use crate::{
+    prefix_set::TriePrefixSetsMut,
+    updates::{StorageTrieUpdates, TrieUpdates},
+    BranchNodeCompact, HashedPostState, HashedStorage, Nibbles,
+};
+use std::collections::{HashMap, HashSet};
 
 /// Inputs for trie-related computations.
 #[derive(Default, Debug)]
@@ -72,4 +77,288 @@ impl TrieInput {
         self.nodes.extend_ref(nodes);
         self.state.extend_ref(state);
     }
+
+    /// Returns the changes of this input that are not yet part of `prior`, with prefix sets
+    /// constructed from the changed state.
+    ///
+    /// This input is expected to be built on top of `prior`, e.g. by appending the state and
+    /// nodes of a subsequent block. In that case, appending the returned nodes and state to
+    /// `prior` with [`Self::append_cached`] reconstructs the nodes and state of this input.
+    pub fn delta(&self, prior: &Self) -> Self {
+        let state = state_delta(&self.state, &prior.state);
+        let prefix_sets = state.construct_prefix_sets();
+        Self { nodes: trie_updates_delta(&self.nodes, &prior.nodes), state, prefix_sets }
+    }
+}
+
+/// Returns the accounts and storage slots of `state` that are missing from or differ in `prior`.
+///
+/// The storage of an account is returned as a whole if it was wiped, or if it no longer contains
+/// some of the prior slots, since a delta of only the changed slots can't undo those.
+fn state_delta(state: &HashedPostState, prior: &HashedPostState) -> HashedPostState {
+    let accounts = state
+        .accounts
+        .iter()
+        .filter(|(hashed_address, account)| prior.accounts.get(*hashed_address) != Some(account))
+        .map(|(hashed_address, account)| (*hashed_address, *account))
+        .collect();
+
+    let mut storages = HashMap::default();
+    for (hashed_address, storage) in &state.storages {
+        let delta = match prior.storages.get(hashed_address) {
+            Some(prior)
+                if !storage.wiped &&
+                    prior.storage.keys().all(|slot| storage.storage.contains_key(slot)) =>
+            {
+                HashedStorage::from_iter(
+                    false,
+                    storage
+                        .storage
+                        .iter()
+                        .filter(|(slot, value)| prior.storage.get(*slot) != Some(value))
+                        .map(|(slot, value)| (*slot, *value)),
+                )
+            }
+            _ => storage.clone(),
+        };
+        if delta.wiped || !delta.storage.is_empty() {
+            storages.insert(*hashed_address, delta);
+        }
+    }
+
+    HashedPostState { accounts, storages }
+}
+
+/// Returns the trie updates that need to be applied to `prior` to obtain `updates`.
+///
+/// The updates of a storage trie are returned as a whole if it was deleted, or if they no longer
+/// contain some of the prior removed nodes, since removals can't be undone by extending.
+fn trie_updates_delta(updates: &TrieUpdates, prior: &TrieUpdates) -> TrieUpdates {
+    let (account_nodes, removed_nodes) = nodes_delta(
+        &updates.account_nodes,
+        &updates.removed_nodes,
+        &prior.account_nodes,
+        &prior.removed_nodes,
+    );
+
+    let mut storage_tries = HashMap::default();
+    for (hashed_address, storage_trie) in &updates.storage_tries {
+        let delta = match prior.storage_tries.get(hashed_address) {
+            Some(prior)
+                if !storage_trie.is_deleted &&
+                    prior.removed_nodes.is_subset(&storage_trie.removed_nodes) =>
+            {
+                let (storage_nodes, removed_nodes) = nodes_delta(
+                    &storage_trie.storage_nodes,
+                    &storage_trie.removed_nodes,
+                    &prior.storage_nodes,
+                    &prior.removed_nodes,
+                );
+                StorageTrieUpdates { is_deleted: false, storage_nodes, removed_nodes }
+            }
+            _ => storage_trie.clone(),
+        };
+        if !delta.is_empty() {
+            storage_tries.insert(*hashed_address, delta);
+        }
+    }
+
+    TrieUpdates { account_nodes, removed_nodes, storage_tries }
+}
+
+/// Returns the updated and removed nodes that, once extended into the prior ones, result in the
+/// given ones.
+///
+/// Nodes that are present in the prior nodes but no longer in the given ones are marked as
+/// removed. Nodes that are removed by the delta but present in the given nodes are re-added.
+fn nodes_delta(
+    nodes: &HashMap<Nibbles, BranchNodeCompact>,
+    removed: &HashSet<Nibbles>,
+    prior_nodes: &HashMap<Nibbles, BranchNodeCompact>,
+    prior_removed: &HashSet<Nibbles>,
+) -> (HashMap<Nibbles, BranchNodeCompact>, HashSet<Nibbles>) {
+    let removed_delta = removed
+        .iter()
+        .filter(|nibbles| !prior_removed.contains(*nibbles))
+        .chain(prior_nodes.keys().filter(|nibbles| !nodes.contains_key(*nibbles)))
+        .cloned()
+        .collect::<HashSet<_>>();
+    let nodes_delta = nodes
+        .iter()
+        .filter(|(nibbles, node)| {
+            prior_nodes.get(*nibbles) != Some(node) || removed_delta.contains(*nibbles)
+        })
+        .map(|(nibbles, node)| (nibbles.clone(), node.clone()))
+        .collect();
+    (nodes_delta, removed_delta)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{B256, U256};
+    use reth_primitives::Account;
+
+    fn branch(hash: u8) -> BranchNodeCompact {
+        BranchNodeCompact::new(0b11, 0, 0b11, vec![B256::with_last_byte(hash); 2], None)
+    }
+
+    fn block_one() -> (TrieUpdates, HashedPostState) {
+        let nodes = TrieUpdates {
+            account_nodes: HashMap::from([
+                (Nibbles::from_nibbles([0x1]), branch(1)),
+                (Nibbles::from_nibbles([0x2]), branch(2)),
+            ]),
+            removed_nodes: HashSet::default(),
+            storage_tries: HashMap::from([(
+                B256::with_last_byte(1),
+                StorageTrieUpdates {
+                    is_deleted: false,
+                    storage_nodes: HashMap::from([(Nibbles::from_nibbles([0x1]), branch(1))]),
+                    removed_nodes: HashSet::default(),
+                },
+            )]),
+        };
+        let state = HashedPostState::default()
+            .with_accounts([
+                (B256::with_last_byte(1), Some(Account { nonce: 1, ..Default::default() })),
+                (B256::with_last_byte(2), Some(Account { nonce: 1, ..Default::default() })),
+            ])
+            .with_storages([(
+                B256::with_last_byte(1),
+                HashedStorage::from_iter(
+                    false,
+                    [
+                        (B256::with_last_byte(1), U256::from(1)),
+                        (B256::with_last_byte(2), U256::from(1)),
+                    ],
+                ),
+            )]);
+        (nodes, state)
+    }
+
+    fn block_two() -> (TrieUpdates, HashedPostState) {
+        let nodes = TrieUpdates {
+            account_nodes: HashMap::from([
+                (Nibbles::from_nibbles([0x2]), branch(3)),
+                (Nibbles::from_nibbles([0x3]), branch(3)),
+            ]),
+            removed_nodes: HashSet::from([Nibbles::from_nibbles([0x1])]),
+            storage_tries: HashMap::from([(
+                B256::with_last_byte(1),
+                StorageTrieUpdates::deleted(),
+            )]),
+        };
+        let state = HashedPostState::default()
+            .with_accounts([
+                // unchanged
+                (B256::with_last_byte(1), Some(Account { nonce: 1, ..Default::default() })),
+                // updated
+                (B256::with_last_byte(2), Some(Account { nonce: 2, ..Default::default() })),
+                // created
+                (B256::with_last_byte(3), Some(Account { nonce: 1, ..Default::default() })),
+            ])
+            .with_storages([(
+                B256::with_last_byte(1),
+                HashedStorage::from_iter(
+                    false,
+                    [
+                        (B256::with_last_byte(1), U256::from(1)),
+                        (B256::with_last_byte(2), U256::from(2)),
+                    ],
+                ),
+            )]);
+        (nodes, state)
+    }
+
+    #[test]
+    fn delta_over_sequential_blocks() {
+        let (nodes_one, state_one) = block_one();
+        let (nodes_two, state_two) = block_two();
+
+        let mut prior = TrieInput::default();
+        prior.append_cached(nodes_one.clone(), state_one.clone());
+        let mut input = TrieInput::default();
+        input.append_cached(nodes_one, state_one);
+        input.append_cached(nodes_two, state_two);
+
+        let delta = input.delta(&prior);
+        assert_eq!(
+            delta.state,
+            HashedPostState::default()
+                .with_accounts([
+                    (B256::with_last_byte(2), Some(Account { nonce: 2, ..Default::default() })),
+                    (B256::with_last_byte(3), Some(Account { nonce: 1, ..Default::default() })),
+                ])
+                .with_storages([(
+                    B256::with_last_byte(1),
+                    HashedStorage::from_iter(false, [(B256::with_last_byte(2), U256::from(2))]),
+                )])
+        );
+        assert_eq!(
+            delta.nodes.account_nodes,
+            HashMap::from([
+                (Nibbles::from_nibbles([0x2]), branch(3)),
+                (Nibbles::from_nibbles([0x3]), branch(3)),
+            ])
+        );
+        assert_eq!(delta.nodes.removed_nodes, HashSet::from([Nibbles::from_nibbles([0x1])]));
+        assert_eq!(
+            delta.nodes.storage_tries,
+            HashMap::from([(B256::with_last_byte(1), StorageTrieUpdates::deleted())])
+        );
+
+        prior.append_cached(delta.nodes, delta.state);
+        assert_eq!(prior.nodes, input.nodes);
+        assert_eq!(prior.state, input.state);
+    }
+
+    #[test]
+    fn delta_over_repeated_wipe() {
+        let hashed_address = B256::with_last_byte(1);
+        let wiped = |nodes: HashMap<Nibbles, BranchNodeCompact>, slot: u8| {
+            let nodes = TrieUpdates {
+                storage_tries: HashMap::from([(
+                    hashed_address,
+                    StorageTrieUpdates {
+                        is_deleted: true,
+                        storage_nodes: nodes,
+                        removed_nodes: HashSet::from([Nibbles::from_nibbles([slot])]),
+                    },
+                )]),
+                ..Default::default()
+            };
+            let state = HashedPostState::default().with_storages([(
+                hashed_address,
+                HashedStorage::from_iter(true, [(B256::with_last_byte(slot), U256::from(slot))]),
+            )]);
+            (nodes, state)
+        };
+        let (nodes_one, state_one) =
+            wiped(HashMap::from([(Nibbles::from_nibbles([0x1]), branch(1))]), 1);
+        let (nodes_two, state_two) =
+            wiped(HashMap::from([(Nibbles::from_nibbles([0x2]), branch(2))]), 2);
+
+        let mut prior = TrieInput::default();
+        prior.append_cached(nodes_one.clone(), state_one.clone());
+        let mut input = TrieInput::default();
+        input.append_cached(nodes_one, state_one);
+        input.append_cached(nodes_two, state_two);
+
+        let delta = input.delta(&prior);
+        prior.append_cached(delta.nodes, delta.state);
+        assert_eq!(prior.nodes, input.nodes);
+        assert_eq!(prior.state, input.state);
+    }
+
+    #[test]
+    fn delta_of_same_input_is_empty() {
+        let (nodes, state) = block_one();
+        let mut input = TrieInput::default();
+        input.append_cached(nodes, state);
+
+        let delta = input.delta(&input);
+        assert!(delta.nodes.is_empty());
+        assert!(delta.state.is_empty());
+    }
 }
@@ -14,7 +14,7 @@ use alloy_primitives::{
 use alloy_rlp::{BufMut, Encodable};
 use reth_execution_errors::trie::StateProofError;
 use reth_trie_common::{
-    proof::ProofRetainer, AccountProof, MultiProof, StorageMultiProof, TrieAccount,
+    proof::ProofRetainer, AccountProof, MultiProof, ProofTargets, StorageMultiProof, TrieAccount,
 };
 
 /// A struct for generating merkle proofs.
@@ -79,7 +79,7 @@ where
         slots: &[B256],
     ) -> Result<AccountProof, StateProofError> {
         Ok(self
-            .multiproof(HashMap::from_iter([(
+            .multiproof(ProofTargets::from_iter([(
                 keccak256(address),
                 slots.iter().map(keccak256).collect(),
             )]))?
@@ -87,20 +87,17 @@ where
     }
 
     /// Generate a state multiproof according to specified targets.
-    pub fn multiproof(
-        mut self,
-        mut targets: HashMap<B256, HashSet<B256>>,
-    ) -> Result<MultiProof, StateProofError> {
+    pub fn multiproof(mut self, mut targets: ProofTargets) -> Result<MultiProof, StateProofError> {
         let hashed_account_cursor = self.hashed_cursor_factory.hashed_account_cursor()?;
         let trie_cursor = self.trie_cursor_factory.account_trie_cursor()?;
 
         // Create the walker.
         let mut prefix_set = self.prefix_sets.account_prefix_set.clone();
-        prefix_set.extend_keys(targets.keys().map(Nibbles::unpack));
+        prefix_set.extend_keys(targets.accounts().map(Nibbles::unpack));
         let walker = TrieWalker::new(trie_cursor, prefix_set.freeze());
 
         // Create a hash builder to rebuild the root node since it is not available in the database.
-        let retainer = targets.keys().map(Nibbles::unpack).collect();
+        let retainer = targets.accounts().map(Nibbles::unpack).collect();
         let mut hash_builder = HashBuilder::default().with_proof_retainer(retainer);
 
         let mut storages = HashMap::default();
@@ -15,7 +15,8 @@ use alloy_rlp::{BufMut, Decodable, Encodable};
 use itertools::{Either, Itertools};
 use reth_execution_errors::{StateProofError, TrieWitnessError};
 use reth_trie_common::{
-    BranchNode, HashBuilder, Nibbles, StorageMultiProof, TrieAccount, TrieNode, CHILD_INDEX_RANGE,
+    BranchNode, HashBuilder, Nibbles, ProofTargets, StorageMultiProof, TrieAccount, TrieNode,
+    CHILD_INDEX_RANGE,
 };
 use std::collections::BTreeMap;
 
@@ -179,7 +180,8 @@ where
             // Right pad the target with 0s.
             let mut padded_key = key.pack();
             padded_key.resize(32, 0);
-            let targets = HashMap::from_iter([(B256::from_slice(&padded_key), HashSet::default())]);
+            let mut targets = ProofTargets::default();
+            targets.add_account(B256::from_slice(&padded_key));
             let proof =
                 Proof::new(self.trie_cursor_factory.clone(), self.hashed_cursor_factory.clone())
                     .with_prefix_sets_mut(self.prefix_sets.clone())
@@ -198,13 +200,10 @@ where
     /// Retrieve proof targets for incoming hashed state.
     /// This method will aggregate all accounts and slots present in the hash state as well as
     /// select all existing slots from the database for the accounts that have been destroyed.
-    fn get_proof_targets(
-        &self,
-        state: &HashedPostState,
-    ) -> Result<HashMap<B256, HashSet<B256>>, StateProofError> {
-        let mut proof_targets = HashMap::default();
+    fn get_proof_targets(&self, state: &HashedPostState) -> Result<ProofTargets, StateProofError> {
+        let mut proof_targets = ProofTargets::default();
         for hashed_address in state.accounts.keys() {
-            proof_targets.insert(*hashed_address, HashSet::default());
+            proof_targets.add_account(*hashed_address);
         }
         for (hashed_address, storage) in &state.storages {
             let mut storage_keys = storage.storage.keys().copied().collect::<HashSet<_>>();
@@ -219,7 +218,7 @@ where
                     current_entry = storage_cursor.next()?;
                 }
             }
-            proof_targets.insert(*hashed_address, storage_keys);
+            proof_targets.add_slots(*hashed_address, storage_keys);
         }
         Ok(proof_targets)
     }
@@ -0,0 +1,206 @@
+//! Example of how to compare two reth databases table by table.
+//!
+//! Run with
+//!
+//! ```not_rust
+//! cargo run -p example-db-diff -- <datadir a> <datadir b> [--tables Headers,PlainStorageState]
+//! ```
+//!
+//! Both databases are opened in read-only mode and every table is walked with one cursor per
+//! database, in lockstep. Rows are compared in their raw, encoded form, so nothing is ever loaded
+//! into memory beyond the current row, or the current key of a dupsort table, whose values are
+//! compared as a group.
+//!
+//! For every table this reports the keys that are present in only one of the databases and the
+//! keys whose values differ.
+
+#![cfg_attr(not(test), warn(unused_crate_dependencies))]
+
+use alloy_primitives::hex;
+use clap::Parser;
+use reth_db::{
+    open_db_read_only, DatabaseEnv, RawDupSort, RawKey, RawTable, RawValue, TableViewer, Tables,
+};
+use reth_db_api::{
+    cursor::{DbCursorRO, DbDupCursorRO},
+    database::Database,
+    table::{DupSort, Key, Table, Value},
+    transaction::DbTx,
+    DatabaseError,
+};
+use std::{cmp::Ordering, path::PathBuf};
+
+#[derive(Debug, Parser)]
+struct Args {
+    /// The datadir of the first database.
+    a: PathBuf,
+    /// The datadir of the second database.
+    b: PathBuf,
+    /// The tables to compare, separated by commas. All tables are compared by default.
+    #[arg(long, value_delimiter = ',')]
+    tables: Vec<Tables>,
+    /// The maximum number of differences printed per table.
+    #[arg(long, default_value_t = 10)]
+    max_printed: usize,
+}
+
+fn main() -> eyre::Result<()> {
+    let args = Args::parse();
+
+    // Opens RO handles to both database files.
+    let a = open_db_read_only(args.a.join("db").as_path(), Default::default())?;
+    let b = open_db_read_only(args.b.join("db").as_path(), Default::default())?;
+
+    let tables = if args.tables.is_empty() { Tables::ALL.to_vec() } else { args.tables };
+
+    let mut total = TableDiff::default();
+    for table in tables {
+        let diff = table.view(&DiffViewer { a: &a, b: &b, max_printed: args.max_printed })?;
+        println!(
+            "{:<28} keys: {:>12}  only in a: {:>8}  only in b: {:>8}  mismatched: {:>8}",
+            table.name(),
+            diff.keys,
+            diff.only_a,
+            diff.only_b,
+            diff.mismatched
+        );
+        total.add(&diff);
+    }
+
+    println!(
+        "Compared {} keys, {} only in a, {} only in b, {} mismatched",
+        total.keys, total.only_a, total.only_b, total.mismatched
+    );
+
+    Ok(())
+}
+
+/// The differences found in a single table.
+#[derive(Debug, Default)]
+struct TableDiff {
+    /// The number of distinct keys across both databases.
+    keys: u64,
+    /// Keys present only in the first database.
+    only_a: u64,
+    /// Keys present only in the second database.
+    only_b: u64,
+    /// Keys present in both databases, with different values.
+    mismatched: u64,
+}
+
+impl TableDiff {
+    fn add(&mut self, other: &Self) {
+        self.keys += other.keys;
+        self.only_a += other.only_a;
+        self.only_b += other.only_b;
+        self.mismatched += other.mismatched;
+    }
+
+    fn total(&self) -> u64 {
+        self.only_a + self.only_b + self.mismatched
+    }
+}
+
+/// Compares a table of both databases.
+struct DiffViewer<'a> {
+    a: &'a DatabaseEnv,
+    b: &'a DatabaseEnv,
+    max_printed: usize,
+}
+
+impl DiffViewer<'_> {
+    /// Walks two iterators over `(key, value)` pairs sorted by key in lockstep, and counts the
+    /// keys that are missing on either side or have different values.
+    fn diff<V: PartialEq>(
+        &self,
+        mut a: impl Iterator<Item = Result<(Vec<u8>, V), DatabaseError>>,
+        mut b: impl Iterator<Item = Result<(Vec<u8>, V), DatabaseError>>,
+    ) -> eyre::Result<TableDiff> {
+        let mut diff = TableDiff::default();
+        let report = |diff: &TableDiff, kind: &str, key: &[u8]| {
+            if diff.total() <= self.max_printed as u64 {
+                println!("    {kind}: 0x{}", hex::encode(key));
+            }
+        };
+
+        let (mut next_a, mut next_b) = (a.next().transpose()?, b.next().transpose()?);
+        loop {
+            let ordering = match (&next_a, &next_b) {
+                (None, None) => break,
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (Some((key_a, _)), Some((key_b, _))) => key_a.cmp(key_b),
+            };
+            diff.keys += 1;
+
+            match ordering {
+                Ordering::Less => {
+                    let (key, _) = next_a.take().expect("present");
+                    diff.only_a += 1;
+                    report(&diff, "only in a", &key);
+                    next_a = a.next().transpose()?;
+                }
+                Ordering::Greater => {
+                    let (key, _) = next_b.take().expect("present");
+                    diff.only_b += 1;
+                    report(&diff, "only in b", &key);
+                    next_b = b.next().transpose()?;
+                }
+                Ordering::Equal => {
+                    let (key, value_a) = next_a.take().expect("present");
+                    let (_, value_b) = next_b.take().expect("present");
+                    if value_a != value_b {
+                        diff.mismatched += 1;
+                        report(&diff, "mismatched", &key);
+                    }
+                    next_a = a.next().transpose()?;
+                    next_b = b.next().transpose()?;
+                }
+            }
+        }
+
+        Ok(diff)
+    }
+}
+
+impl TableViewer<TableDiff> for DiffViewer<'_> {
+    type Error = eyre::Report;
+
+    fn view<T: Table>(&self) -> Result<TableDiff, Self::Error> {
+        let (mut tx_a, mut tx_b) = (self.a.tx()?, self.b.tx()?);
+        tx_a.disable_long_read_transaction_safety();
+        tx_b.disable_long_read_transaction_safety();
+        let mut cursor_a = tx_a.cursor_read::<RawTable<T>>()?;
+        let mut cursor_b = tx_b.cursor_read::<RawTable<T>>()?;
+
+        self.diff(
+            cursor_a.walk(None)?.map(|entry| entry.map(raw_row)),
+            cursor_b.walk(None)?.map(|entry| entry.map(raw_row)),
+        )
+    }
+
+    fn view_dupsort<T: DupSort>(&self) -> Result<TableDiff, Self::Error> {
+        let (mut tx_a, mut tx_b) = (self.a.tx()?, self.b.tx()?);
+        tx_a.disable_long_read_transaction_safety();
+        tx_b.disable_long_read_transaction_safety();
+        let mut cursor_a = tx_a.cursor_dup_read::<RawDupSort<T>>()?;
+        let mut cursor_b = tx_b.cursor_dup_read::<RawDupSort<T>>()?;
+
+        self.diff(
+            cursor_a.walk_grouped(None)?.map(|entry| entry.map(raw_group)),
+            cursor_b.walk_grouped(None)?.map(|entry| entry.map(raw_group)),
+        )
+    }
+}
+
+/// Returns the encoded key and compressed value of a row.
+fn raw_row<K: Key, V: Value>((key, value): (RawKey<K>, RawValue<V>)) -> (Vec<u8>, Vec<u8>) {
+    (key.into_key(), value.into_value())
+}
+
+/// Returns the encoded key and all compressed values of a key of a dupsort table.
+fn raw_group<K: Key, V: Value>(
+    (key, values): (RawKey<K>, Vec<RawValue<V>>),
+) -> (Vec<u8>, Vec<Vec<u8>>) {
+    (key.into_key(), values.into_iter().map(RawValue::into_value).collect())
+}
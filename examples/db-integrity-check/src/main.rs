@@ -0,0 +1,179 @@
+//! Example of how to check the integrity of every table of a reth database.
+//!
+//! Run with
+//!
+//! ```not_rust
+//! RETH_DB_PATH=<path to the datadir> cargo run -p example-db-integrity-check
+//! ```
+//!
+//! For every table this walks all rows in raw mode and checks that:
+//!
+//! - every key decodes, and encodes back to the same bytes;
+//! - every value decompresses, and compresses back to the same bytes;
+//! - keys are strictly increasing, as MDBX orders them by their encoded bytes;
+//! - for dupsort tables, the values of a key (which start with the encoded subkey) are strictly
+//!   increasing as well.
+//!
+//! The database is opened in read-only mode, so it is safe to run against a stopped node.
+
+#![cfg_attr(not(test), warn(unused_crate_dependencies))]
+
+use reth_db::{open_db_read_only, DatabaseEnv, RawKey, RawTable, RawValue, TableViewer, Tables};
+use reth_db_api::{
+    cursor::DbCursorRO,
+    database::Database,
+    table::{Compress, Decode, Decompress, DupSort, Encode, Table},
+    transaction::DbTx,
+};
+use std::path::Path;
+
+fn main() -> eyre::Result<()> {
+    // Opens a RO handle to the database file.
+    let db_path = std::env::var("RETH_DB_PATH")?;
+    let db = open_db_read_only(Path::new(&db_path).join("db").as_path(), Default::default())?;
+
+    let mut total = TableReport::default();
+    for table in Tables::ALL {
+        let report = table.view(&IntegrityChecker { db: &db })?;
+        println!(
+            "{:<28} rows: {:>12}  errors: {:>8}",
+            table.name(),
+            report.rows,
+            report.errors.total()
+        );
+        for (kind, count) in report.errors.iter().filter(|(_, count)| *count > 0) {
+            println!("    {kind}: {count}");
+        }
+        total.rows += report.rows;
+        total.errors.add(&report.errors);
+    }
+
+    println!(
+        "Checked {} rows across {} tables, found {} errors",
+        total.rows,
+        Tables::COUNT,
+        total.errors.total()
+    );
+
+    Ok(())
+}
+
+/// The outcome of checking a single table.
+#[derive(Debug, Default)]
+struct TableReport {
+    /// The number of rows checked.
+    rows: u64,
+    /// The errors found in the table.
+    errors: IntegrityErrors,
+}
+
+/// Error counts by kind.
+#[derive(Debug, Default)]
+struct IntegrityErrors {
+    /// Keys that could not be decoded.
+    key_decode: u64,
+    /// Keys that did not encode back to the same bytes.
+    key_roundtrip: u64,
+    /// Values that could not be decompressed.
+    value_decompress: u64,
+    /// Values that did not compress back to the same bytes.
+    value_roundtrip: u64,
+    /// Keys that were not greater than the previous key.
+    key_order: u64,
+    /// Duplicate values that were not greater than the previous value of the same key.
+    subkey_order: u64,
+}
+
+impl IntegrityErrors {
+    fn iter(&self) -> impl Iterator<Item = (&'static str, u64)> {
+        [
+            ("key decode", self.key_decode),
+            ("key roundtrip", self.key_roundtrip),
+            ("value decompress", self.value_decompress),
+            ("value roundtrip", self.value_roundtrip),
+            ("key order", self.key_order),
+            ("subkey order", self.subkey_order),
+        ]
+        .into_iter()
+    }
+
+    fn total(&self) -> u64 {
+        self.iter().map(|(_, count)| count).sum()
+    }
+
+    fn add(&mut self, other: &Self) {
+        self.key_decode += other.key_decode;
+        self.key_roundtrip += other.key_roundtrip;
+        self.value_decompress += other.value_decompress;
+        self.value_roundtrip += other.value_roundtrip;
+        self.key_order += other.key_order;
+        self.subkey_order += other.subkey_order;
+    }
+}
+
+/// Walks a table and checks the encoding and ordering of every row.
+struct IntegrityChecker<'a> {
+    db: &'a DatabaseEnv,
+}
+
+impl IntegrityChecker<'_> {
+    fn check<T: Table>(&self, dupsort: bool) -> eyre::Result<TableReport> {
+        let mut tx = self.db.tx()?;
+        tx.disable_long_read_transaction_safety();
+        let mut cursor = tx.cursor_read::<RawTable<T>>()?;
+
+        let mut report = TableReport::default();
+        let mut previous: Option<(Vec<u8>, Vec<u8>)> = None;
+        for entry in cursor.walk(None)? {
+            let (key, value): (RawKey<T::Key>, RawValue<T::Value>) = entry?;
+            let (key, value) = (key.into_key(), value.into_value());
+            report.rows += 1;
+
+            match T::Key::decode(&key) {
+                Ok(decoded) => {
+                    if decoded.encode().as_ref() != key.as_slice() {
+                        report.errors.key_roundtrip += 1
+                    }
+                }
+                Err(_) => report.errors.key_decode += 1,
+            }
+
+            match T::Value::decompress(&value) {
+                Ok(decoded) => {
+                    if decoded.compress().as_ref() != value.as_slice() {
+                        report.errors.value_roundtrip += 1
+                    }
+                }
+                Err(_) => report.errors.value_decompress += 1,
+            }
+
+            // MDBX compares keys, and the values of dupsort tables, by their raw bytes.
+            if let Some((previous_key, previous_value)) = &previous {
+                match previous_key.cmp(&key) {
+                    std::cmp::Ordering::Less => {}
+                    std::cmp::Ordering::Equal if dupsort => {
+                        if previous_value >= &value {
+                            report.errors.subkey_order += 1;
+                        }
+                    }
+                    _ => report.errors.key_order += 1,
+                }
+            }
+            previous = Some((key, value));
+        }
+
+        Ok(report)
+    }
+}
+
+impl TableViewer<TableReport> for IntegrityChecker<'_> {
+    type Error = eyre::Report;
+
+    fn view<T: Table>(&self) -> Result<TableReport, Self::Error> {
+        self.check::<T>(false)
+    }
+
+    fn view_dupsort<T: DupSort>(&self) -> Result<TableReport, Self::Error> {
+        self.check::<T>(true)
+    }
+}